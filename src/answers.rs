@@ -0,0 +1,206 @@
+use crate::{error::AocError, files};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use toml::{value::Table, Value};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+// Known good answers, along with answers the site rejected and when the site
+// accepts answers again, stored as
+//
+// wait_until = 1670000000
+//
+// [day1]
+// part1 = "..."
+// part2 = "..."
+// part2_wrong = ["...", "..."]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AnswerStore {
+    answers: BTreeMap<(u8, u8), String>,
+    wrong: BTreeMap<(u8, u8), Vec<String>>,
+    // Seconds since the Unix epoch
+    wait_until: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl AnswerStore {
+    pub fn new() -> AnswerStore {
+        AnswerStore::default()
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<AnswerStore, AocError> {
+        files::read_or_default(path.as_ref(), AnswerStore::from)
+    }
+
+    pub fn from(s: &str) -> Result<AnswerStore, AocError> {
+        let value = s.parse::<Value>().map_err(|e| AocError::toml(s, e))?;
+        let mut store = AnswerStore::new();
+
+        for (key, parts) in value.as_table().unwrap() {
+            if key == "wait_until" {
+                let secs = parts
+                    .as_integer()
+                    .and_then(|t| u64::try_from(t).ok())
+                    .ok_or_else(|| AocError::at_line(0, key, "Expected seconds since 1970"))?;
+                store.wait_until = Some(secs);
+                continue;
+            }
+
+            let error = |field: &str, reason: &str| {
+                AocError::at_line(0, &format!("[{key}] {field}"), reason)
+            };
+            let day = key
+                .strip_prefix("day")
+                .and_then(|d| d.parse::<u8>().ok())
+                .ok_or_else(|| error("", "Unexpected section"))?;
+
+            for part in 1..3 {
+                let key = format!("part{part}");
+                if let Some(answer) = parts.get(&key) {
+                    let answer = answer
+                        .as_str()
+                        .ok_or_else(|| error(&key, "Answer is not a string"))?;
+                    store.record(day, part, answer);
+                }
+
+                let key = format!("part{part}_wrong");
+                if let Some(wrong) = parts.get(&key) {
+                    let wrong = wrong
+                        .as_array()
+                        .and_then(|w| w.iter().map(|a| a.as_str()).collect::<Option<Vec<&str>>>())
+                        .ok_or_else(|| error(&key, "Wrong answers are not strings"))?;
+                    for answer in wrong {
+                        store.record_wrong(day, part, answer);
+                    }
+                }
+            }
+        }
+
+        Ok(store)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), AocError> {
+        fs::write(path.as_ref(), self.to_toml()).map_err(|e| AocError::io(path.as_ref(), e))
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut days = Table::new();
+        let mut insert = |day: u8, key: String, value: Value| {
+            days.entry(format!("day{day}"))
+                .or_insert_with(|| Value::Table(Table::new()))
+                .as_table_mut()
+                .unwrap()
+                .insert(key, value);
+        };
+
+        for ((day, part), answer) in self.answers.iter() {
+            insert(*day, format!("part{part}"), Value::String(answer.clone()));
+        }
+        for ((day, part), wrong) in self.wrong.iter() {
+            let wrong = wrong.iter().cloned().map(Value::String).collect();
+            insert(*day, format!("part{part}_wrong"), Value::Array(wrong));
+        }
+        if let Some(secs) = self.wait_until {
+            days.insert(String::from("wait_until"), Value::Integer(secs as i64));
+        }
+
+        toml::to_string(&Value::Table(days)).unwrap()
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|a| a.as_str())
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: &str) {
+        self.answers.insert((day, part), String::from(answer));
+    }
+
+    pub fn record_wrong(&mut self, day: u8, part: u8, answer: &str) {
+        let wrong = self.wrong.entry((day, part)).or_default();
+        if !wrong.iter().any(|w| w == answer) {
+            wrong.push(String::from(answer));
+        }
+    }
+
+    pub fn is_wrong(&self, day: u8, part: u8, answer: &str) -> bool {
+        self.wrong
+            .get(&(day, part))
+            .is_some_and(|w| w.iter().any(|a| a == answer))
+    }
+
+    // When the site said to wait until before answering again
+    pub fn wait_until(&self) -> Option<SystemTime> {
+        self.wait_until
+            .map(|secs| UNIX_EPOCH + Duration::from_secs(secs))
+    }
+
+    pub fn set_wait_until(&mut self, time: SystemTime) {
+        let secs = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+        // Rounded up, so the wait is never cut short
+        self.wait_until = Some(secs.as_secs() + u64::from(secs.subsec_nanos() > 0));
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Check {
+        match self.get(day, part) {
+            Some(known) if known == answer => Check::Pass,
+            Some(_) => Check::Fail,
+            None => Check::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut store = AnswerStore::new();
+        store.record(1, 1, "24000");
+        store.record(1, 2, "45000");
+        store.record(10, 2, "##..\n..##\n");
+        store.record_wrong(1, 2, "45001");
+        store.record_wrong(1, 2, "44999");
+        store.record_wrong(1, 2, "45001");
+        store.set_wait_until(UNIX_EPOCH + Duration::from_secs(1670000000));
+
+        let loaded = AnswerStore::from(&store.to_toml()).unwrap();
+        assert_eq!(loaded, store);
+        assert_eq!(loaded.get(10, 2), Some("##..\n..##\n"));
+        assert_eq!(loaded.get(10, 1), None);
+        assert!(loaded.is_wrong(1, 2, "44999"));
+        assert!(!loaded.is_wrong(1, 1, "44999"));
+        assert_eq!(loaded.wrong[&(1, 2)].len(), 2);
+    }
+
+    #[test]
+    fn check() {
+        let store = AnswerStore::from("[day3]\npart1 = \"157\"").unwrap();
+        assert_eq!(store.check(3, 1, "157"), Check::Pass);
+        assert_eq!(store.check(3, 1, "158"), Check::Fail);
+        assert_eq!(store.check(3, 2, "70"), Check::Unknown);
+    }
+
+    #[test]
+    fn bad_section() {
+        assert!(AnswerStore::from("[dayX]\npart1 = \"1\"").is_err());
+        assert!(AnswerStore::from("[day1]\npart1 = 1").is_err());
+        assert!(AnswerStore::from("[day1]\npart1_wrong = [1]").is_err());
+
+        let e = AnswerStore::from("[day1]\npart1 = \"1\"\n[day2]\npart1 = 2").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Parse error: Answer is not a string in \"[day2] part1\""
+        );
+    }
+}
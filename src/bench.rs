@@ -0,0 +1,291 @@
+use crate::{
+    days::{Day, Params},
+    error::AocError,
+    files,
+};
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use toml::{value::Table, Value};
+
+pub const HISTORY_FILE: &str = "bench_history.toml";
+
+pub const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    // Median absolute deviation from the median
+    pub mad: Duration,
+}
+
+fn median(sorted: &[Duration]) -> Duration {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2
+    } else {
+        sorted[mid]
+    }
+}
+
+impl Stats {
+    pub fn from(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "Need at least one sample");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let median = median(&sorted);
+
+        let mut deviations = sorted
+            .iter()
+            .map(|s| s.abs_diff(median))
+            .collect::<Vec<Duration>>();
+        deviations.sort();
+
+        Stats {
+            median,
+            min: sorted[0],
+            mad: self::median(&deviations),
+        }
+    }
+}
+
+pub struct DayBench {
+    pub day: u8,
+    // Indexed the same as STAGES
+    pub stages: [Stats; 3],
+}
+
+fn time<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let start = Instant::now();
+    let result = black_box(f());
+    (result, start.elapsed())
+}
+
+// Each iteration parses the input again, then runs both parts on the result
+pub fn bench(
+    day: &Day,
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> Result<DayBench, AocError> {
+    let params = Params::new();
+    let mut samples = [(); 3].map(|_| Vec::with_capacity(iterations));
+
+    for i in 0..warmup + iterations {
+        let (parsed, parse) = time(|| (day.parse)(black_box(input)));
+        let parsed = parsed?;
+        let (answer, part1) = time(|| (day.part1)(&parsed, &params));
+        answer?;
+        let (answer, part2) = time(|| (day.part2)(&parsed, &params));
+        answer?;
+
+        if i >= warmup {
+            for (s, d) in samples.iter_mut().zip([parse, part1, part2]) {
+                s.push(d);
+            }
+        }
+    }
+
+    Ok(DayBench {
+        day: day.day,
+        stages: samples.map(|s| Stats::from(&s)),
+    })
+}
+
+// Median time in nanoseconds of every stage for each previous run, stored as
+//
+// [[day1]]
+// time = 1671000000
+// parse = 1234
+// part1 = 567
+// part2 = 890
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct History {
+    runs: BTreeMap<u8, Vec<Table>>,
+}
+
+impl History {
+    pub fn new() -> History {
+        History::default()
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<History, AocError> {
+        files::read_or_default(path.as_ref(), History::from)
+    }
+
+    pub fn from(s: &str) -> Result<History, AocError> {
+        let value = s.parse::<Value>().map_err(|e| AocError::toml(s, e))?;
+        let mut history = History::new();
+
+        for (key, runs) in value.as_table().unwrap() {
+            let error = |reason: &str| AocError::at_line(0, &format!("[[{key}]]"), reason);
+            let day = key
+                .strip_prefix("day")
+                .and_then(|d| d.parse::<u8>().ok())
+                .ok_or_else(|| error("Unexpected section"))?;
+            let runs = runs
+                .as_array()
+                .and_then(|r| r.iter().map(|t| t.as_table().cloned()).collect())
+                .ok_or_else(|| error("Expected [[dayN]] runs"))?;
+
+            history.runs.insert(day, runs);
+        }
+
+        Ok(history)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), AocError> {
+        fs::write(path.as_ref(), self.to_toml()).map_err(|e| AocError::io(path.as_ref(), e))
+    }
+
+    pub fn to_toml(&self) -> String {
+        let days = self
+            .runs
+            .iter()
+            .map(|(day, runs)| {
+                let runs = runs.iter().cloned().map(Value::Table).collect();
+                (format!("day{day}"), Value::Array(runs))
+            })
+            .collect::<Table>();
+
+        toml::to_string(&Value::Table(days)).unwrap()
+    }
+
+    // Median of the most recent run for a stage
+    pub fn last(&self, day: u8, stage: &str) -> Option<Duration> {
+        let ns = self.runs.get(&day)?.last()?.get(stage)?.as_integer()?;
+        Some(Duration::from_nanos(ns as u64))
+    }
+
+    pub fn push(&mut self, bench: &DayBench) {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|t| t.as_secs())
+            .unwrap_or(0);
+
+        let mut run = Table::new();
+        run.insert(String::from("time"), Value::Integer(time as i64));
+        for (stage, stats) in STAGES.iter().zip(bench.stages.iter()) {
+            run.insert(
+                String::from(*stage),
+                Value::Integer(stats.median.as_nanos() as i64),
+            );
+        }
+
+        self.runs.entry(bench.day).or_default().push(run);
+    }
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let ns = d.as_nanos();
+    if ns < 1_000 {
+        format!("{ns}ns")
+    } else if ns < 1_000_000 {
+        format!("{:.1}µs", ns as f64 / 1e3)
+    } else if ns < 1_000_000_000 {
+        format!("{:.1}ms", ns as f64 / 1e6)
+    } else {
+        format!("{:.2}s", d.as_secs_f64())
+    }
+}
+
+fn change(previous: Option<Duration>, now: Duration) -> String {
+    match previous {
+        Some(p) if !p.is_zero() => {
+            let pct = (now.as_secs_f64() - p.as_secs_f64()) / p.as_secs_f64() * 100.0;
+            format!("{pct:+.1}%")
+        }
+        _ => String::from("-"),
+    }
+}
+
+// Compare against the history before pushing the new results
+pub fn table(benches: &[DayBench], history: &History) -> String {
+    let mut s = String::new();
+    writeln!(
+        s,
+        "{:<4} {:<6} {:>9} {:>9} {:>9} {:>8}",
+        "Day", "Stage", "Median", "Min", "MAD", "Change"
+    )
+    .unwrap();
+
+    for bench in benches {
+        for (stage, stats) in STAGES.iter().zip(bench.stages.iter()) {
+            writeln!(
+                s,
+                "{:<4} {:<6} {:>9} {:>9} {:>9} {:>8}",
+                bench.day,
+                stage,
+                format_duration(stats.median),
+                format_duration(stats.min),
+                format_duration(stats.mad),
+                change(history.last(bench.day, stage), stats.median)
+            )
+            .unwrap();
+        }
+    }
+
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    fn ms(v: &[u64]) -> Vec<Duration> {
+        v.iter().map(|m| Duration::from_millis(*m)).collect()
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::from(&ms(&[5, 1, 3, 100, 4]));
+        assert_eq!(stats.median, Duration::from_millis(4));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        // Deviations: 1, 3, 1, 96, 0
+        assert_eq!(stats.mad, Duration::from_millis(1));
+
+        let stats = Stats::from(&ms(&[2, 4]));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mad, Duration::from_millis(1));
+    }
+
+    #[test]
+    fn history_round_trip() {
+        let bench = bench(
+            days::get(6).unwrap(),
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+            1,
+            3,
+        )
+        .unwrap();
+        let mut history = History::new();
+        assert_eq!(history.last(6, "part1"), None);
+
+        history.push(&bench);
+        let loaded = History::from(&history.to_toml()).unwrap();
+        assert_eq!(loaded, history);
+        assert_eq!(loaded.last(6, "part1"), Some(bench.stages[1].median));
+        assert!(table(&[bench], &loaded).contains("+0.0%"));
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_340)), "12.3µs");
+        assert_eq!(format_duration(Duration::from_micros(4_560)), "4.6ms");
+        assert_eq!(format_duration(Duration::from_millis(2_500)), "2.50s");
+        assert_eq!(
+            change(Some(Duration::from_millis(10)), Duration::from_millis(8)),
+            "-20.0%"
+        );
+        assert_eq!(change(None, Duration::from_millis(8)), "-");
+    }
+}
@@ -0,0 +1,175 @@
+use aoc2022::{
+    answers::{AnswerStore, Check, ANSWERS_FILE},
+    bench::{self, History, HISTORY_FILE},
+    client::Client,
+    days::Params,
+    error::AocError,
+    input, runner, scaffold,
+    submit::{self, Verdict},
+};
+use std::{env, path::Path, process};
+
+const USAGE: &str = "Usage: aoc <command> [day|all] [options]
+
+Commands:
+  run     Solve and check the answers against answers.toml
+  record  Solve and store the new answers in answers.toml
+  bench   Time the parse, part 1 and part 2 stages
+  submit  Send an answer, e.g. aoc submit 14 2
+  new     Create the files for a new day, run from the repository root
+
+Record options:
+  --force          Replace stored answers that differ
+
+Bench options:
+  -n <iterations>  Timed runs per day (default 10)
+  -w <warmup>      Untimed runs before timing (default 2)";
+
+fn run(command: &str, days: &str, options: &[String]) -> Result<bool, AocError> {
+    let mut store = AnswerStore::load(ANSWERS_FILE)?;
+    let mut results = Vec::new();
+
+    for day in runner::select_days(days)? {
+        results.append(&mut match input::cached(day.day) {
+            Ok(input) => runner::solve(day, &input, &store),
+            Err(e) => runner::failed(day, &e),
+        });
+    }
+
+    // Checked against the answers stored before this run
+    print!("{}", runner::table(&results, &store));
+    let mut ok = results.iter().all(|r| r.answer.is_ok());
+
+    if command == "record" {
+        let force = options.iter().any(|o| o == "--force");
+        let mut recorded = 0;
+        for r in results.iter() {
+            let Ok(answer) = &r.answer else { continue };
+            match r.check {
+                Check::Pass => {}
+                Check::Fail if !force => {
+                    eprintln!(
+                        "Day {} part {}: kept the stored answer, use --force to replace it",
+                        r.day, r.part
+                    );
+                    ok = false;
+                }
+                Check::Fail | Check::Unknown => {
+                    store.record(r.day, r.part, answer);
+                    recorded += 1;
+                }
+            }
+        }
+        store.save(ANSWERS_FILE)?;
+        println!("Recorded {recorded} answers");
+    } else {
+        ok &= results.iter().all(|r| r.check != Check::Fail);
+    }
+
+    Ok(ok)
+}
+
+fn option(options: &[String], flag: &str, default: usize) -> Result<usize, AocError> {
+    match options.iter().position(|o| o == flag) {
+        Some(i) => options
+            .get(i + 1)
+            .and_then(|v| v.parse().ok())
+            .ok_or(AocError::Other(format!("Expected a number after {flag}"))),
+        None => Ok(default),
+    }
+}
+
+fn run_bench(days: &str, options: &[String]) -> Result<bool, AocError> {
+    let iterations = option(options, "-n", 10)?.max(1);
+    let warmup = option(options, "-w", 2)?;
+    let mut history = History::load(HISTORY_FILE)?;
+    let mut benches = Vec::new();
+    let mut ok = true;
+
+    // Report a day that cannot run and carry on timing the others
+    for day in runner::select_days(days)? {
+        match input::cached(day.day).and_then(|input| bench::bench(day, &input, warmup, iterations))
+        {
+            Ok(b) => benches.push(b),
+            Err(e) => {
+                eprintln!("Day {}: {e}", day.day);
+                ok = false;
+            }
+        }
+    }
+
+    print!("{}", bench::table(&benches, &history));
+
+    for b in benches.iter() {
+        history.push(b);
+    }
+    history.save(HISTORY_FILE)?;
+
+    Ok(ok)
+}
+
+fn run_submit(days: &str, options: &[String]) -> Result<bool, AocError> {
+    let part = match options.first().map(|p| p.as_str()) {
+        Some("1") => 1,
+        Some("2") => 2,
+        _ => return Err(AocError::from("Expected part 1 or 2")),
+    };
+    let [day] = runner::select_days(days)?[..] else {
+        return Err(AocError::from("Submit one day at a time"));
+    };
+
+    let input = input::cached(day.day)?;
+    let solve = if part == 1 { day.part1 } else { day.part2 };
+    let answer = solve(&(day.parse)(&input)?, &Params::new())?;
+    println!("Day {} part {part}: {answer}", day.day);
+
+    let mut store = AnswerStore::load(ANSWERS_FILE)?;
+    let verdict = submit::submit(&Client::from_env(), &mut store, day.day, part, &answer)?;
+    store.save(ANSWERS_FILE)?;
+
+    println!("{verdict}");
+    Ok(verdict == Verdict::Correct)
+}
+
+fn run_new(day: &str) -> Result<bool, AocError> {
+    let day = day
+        .parse::<u8>()
+        .ok()
+        .filter(|d| (1..=25).contains(d))
+        .ok_or(AocError::Other(format!(
+            "Expected a day from 1 to 25, got '{day}'"
+        )))?;
+
+    for path in scaffold::new_day(Path::new("."), day)? {
+        println!("Wrote {}", path.display());
+    }
+
+    Ok(true)
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    let command = args.first().map(|s| s.as_str()).unwrap_or("");
+    let days = args.get(1).map(|s| s.as_str()).unwrap_or("all");
+    let options = args.get(2..).unwrap_or_default();
+
+    let result = match command {
+        "run" | "record" => run(command, days, options),
+        "bench" => run_bench(days, options),
+        "submit" => run_submit(days, options),
+        "new" => run_new(days),
+        _ => {
+            eprintln!("{USAGE}");
+            process::exit(2);
+        }
+    };
+
+    match result {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
@@ -1,36 +1,19 @@
-use std::fs;
+use aoc2022::{error::AocError, input, iter::TopK};
 
-fn main() {
-    // Part 1
-    println!(
-        "Carrying the most: {:?}",
-        fs::read_to_string("input.txt")
-            .expect("Unable to read file")
-            .split("\n\n")
-            .map(|g| g
-                .split("\n")
-                .map(|i| i.parse::<i32>().unwrap_or(0))
-                .sum::<i32>())
-            .max()
-            .unwrap()
-    );
+fn main() -> Result<(), AocError> {
+    // Stream the input, keeping only the three largest sums
+    let top = input::group_sums::<_, i32>(input::open(1)?)
+        .try_fold(TopK::new(3), |mut top, sum| {
+            top.push(sum?);
+            Ok::<_, AocError>(top)
+        })?
+        .into_sorted_vec();
 
-    // Part 2
-    let mut result = fs::read_to_string("input.txt")
-        .expect("Unable to read file")
-        .split("\n\n")
-        .map(|g| {
-            g.split("\n")
-                .map(|i| i.parse::<i32>().unwrap_or(0))
-                .sum::<i32>()
-        })
-        .collect::<Vec<i32>>();
+    if top.len() < 3 {
+        return Err(AocError::NoSolution(String::from("Fewer than three elves")));
+    }
 
-    result.sort();
-    result.reverse();
-
-    println!(
-        "Three carrying the most: {:?}",
-        result[0..3].iter().sum::<i32>()
-    );
+    println!("Carrying the most: {}", top[0]);
+    println!("Three carrying the most: {}", top.iter().sum::<i32>());
+    Ok(())
 }
@@ -1,18 +1,14 @@
-use std::{collections::HashSet, fs};
+use aoc2022::{error::AocError, input, marker};
 
-fn solve(window_size: usize) -> usize {
-    fs::read_to_string("input.txt")
-        .expect("Unable to read input")
-        .as_bytes()
-        .windows(window_size)
-        .map(|c| c.iter().collect::<HashSet<&u8>>().len())
-        .enumerate()
-        .find(|f| f.1 == window_size)
-        .expect("No solution found")
-        .0
-        + window_size
-}
+fn main() -> Result<(), AocError> {
+    // Both markers in a single pass over the stream
+    let markers = marker::find_markers(input::open(6)?, &[4, 14])?;
 
-fn main() {
-    println!("Part 1: {}\nPart 2: {}", solve(4), solve(14));
+    for (part, (size, marker)) in [4, 14].iter().zip(markers).enumerate() {
+        let marker = marker.ok_or(AocError::NoSolution(format!(
+            "No {size} distinct characters in a row"
+        )))?;
+        println!("Part {}: {marker}", part + 1);
+    }
+    Ok(())
 }
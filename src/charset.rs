@@ -0,0 +1,187 @@
+use crate::error::AocError;
+use std::{
+    fmt::Debug,
+    ops::{BitAnd, BitOr},
+};
+
+// a-z = 1..26, A-Z = 27..52
+pub fn priority(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(c as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(c as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+// Set of ASCII characters, one bit per character
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CharSet {
+    bits: u128,
+}
+
+impl CharSet {
+    pub fn new() -> CharSet {
+        CharSet::default()
+    }
+
+    fn bit(c: char) -> Option<u128> {
+        c.is_ascii().then(|| 1 << c as u32)
+    }
+
+    // Returns true if c was not already in the set, fails for non-ASCII
+    pub fn insert(&mut self, c: char) -> Result<bool, AocError> {
+        let bit = CharSet::bit(c).ok_or(AocError::Other(format!(
+            "CharSet only holds ASCII, got {c:?}"
+        )))?;
+        let added = self.bits & bit == 0;
+        self.bits |= bit;
+        Ok(added)
+    }
+
+    pub fn remove(&mut self, c: char) -> bool {
+        let bit = CharSet::bit(c).unwrap_or_default();
+        let removed = self.bits & bit != 0;
+        self.bits &= !bit;
+        removed
+    }
+
+    pub fn contains(&self, c: char) -> bool {
+        CharSet::bit(c).is_some_and(|bit| self.bits & bit != 0)
+    }
+
+    pub fn intersection(&self, other: &CharSet) -> CharSet {
+        CharSet {
+            bits: self.bits & other.bits,
+        }
+    }
+
+    pub fn union(&self, other: &CharSet) -> CharSet {
+        CharSet {
+            bits: self.bits | other.bits,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    // In ASCII order
+    pub fn iter(&self) -> Chars {
+        Chars { bits: self.bits }
+    }
+
+    pub fn priority_sum(&self) -> u32 {
+        self.iter().filter_map(priority).sum()
+    }
+}
+
+impl TryFrom<&str> for CharSet {
+    type Error = AocError;
+
+    fn try_from(s: &str) -> Result<CharSet, AocError> {
+        let mut set = CharSet::new();
+        for c in s.chars() {
+            set.insert(c)?;
+        }
+        Ok(set)
+    }
+}
+
+impl BitAnd for CharSet {
+    type Output = CharSet;
+
+    fn bitand(self, rhs: CharSet) -> CharSet {
+        self.intersection(&rhs)
+    }
+}
+
+impl BitOr for CharSet {
+    type Output = CharSet;
+
+    fn bitor(self, rhs: CharSet) -> CharSet {
+        self.union(&rhs)
+    }
+}
+
+impl Debug for CharSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+pub struct Chars {
+    bits: u128,
+}
+
+impl Iterator for Chars {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.bits == 0 {
+            return None;
+        }
+
+        let c = self.bits.trailing_zeros() as u8 as char;
+        // Clear the lowest set bit
+        self.bits &= self.bits - 1;
+        Some(c)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl IntoIterator for CharSet {
+    type Item = char;
+    type IntoIter = Chars;
+
+    fn into_iter(self) -> Chars {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn priorities() {
+        assert_eq!(priority('a'), Some(1));
+        assert_eq!(priority('z'), Some(26));
+        assert_eq!(priority('A'), Some(27));
+        assert_eq!(priority('Z'), Some(52));
+        assert_eq!(priority('1'), None);
+    }
+
+    #[test]
+    fn set_operations() {
+        let set = |s| CharSet::try_from(s).unwrap();
+        let mut a = set("vJrwpWtwJgWr");
+        let b = set("hcsFMMfFFhFp");
+        assert_eq!(a & b, set("p"));
+        assert_eq!((a & b).priority_sum(), 16);
+
+        assert_eq!(a.len(), 8);
+        assert!(!a.insert('v').unwrap());
+        assert!(a.insert('~').unwrap());
+        assert!(a.contains('~'));
+        assert!(a.remove('~'));
+
+        // Characters outside ASCII are never in the set
+        assert!(!a.contains('é'));
+        assert!(!a.remove('é'));
+        assert!(a.insert('é').is_err());
+        assert!(CharSet::try_from("abé").is_err());
+
+        let union = set("ba") | set("cb");
+        assert_eq!(union.iter().collect::<String>(), "abc");
+        assert_eq!(format!("{union:?}"), "{'a', 'b', 'c'}");
+        assert!(CharSet::new().is_empty());
+    }
+}
@@ -0,0 +1,83 @@
+use crate::error::AocError;
+use std::{env, fs};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2022;
+
+// The session cookie is read from AOC_SESSION, or from the file named by
+// AOC_SESSION_FILE (.session by default)
+pub const SESSION_FILE: &str = ".session";
+
+const USER_AGENT: &str = "github.com/willtoth/Advent-of-Code-2022";
+
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<&str>) -> Client {
+        Client {
+            base_url: String::from(base_url.trim_end_matches('/')),
+            session: session.map(|s| String::from(s.trim())),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    // AOC_BASE_URL overrides the real site, e.g. to point at a local server
+    pub fn from_env() -> Client {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or(String::from(BASE_URL));
+        let session = env::var("AOC_SESSION").ok().or_else(|| {
+            let file = env::var("AOC_SESSION_FILE").unwrap_or(String::from(SESSION_FILE));
+            fs::read_to_string(file).ok()
+        });
+
+        Client::new(&base_url, session.as_deref())
+    }
+
+    fn session(&self) -> Result<&str, AocError> {
+        self.session
+            .as_deref()
+            .filter(|s| !s.is_empty())
+            .ok_or(AocError::Other(format!(
+                "No session token, set AOC_SESSION or save it in {SESSION_FILE}"
+            )))
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/{YEAR}/day/{day}{path}", self.base_url)
+    }
+
+    // GET, or POST when given a form
+    fn request(&self, url: &str, form: Option<&[(&str, &str)]>) -> Result<String, AocError> {
+        let cookie = format!("session={}", self.session()?);
+        let response = match form {
+            Some(form) => self.agent.post(url).set("Cookie", &cookie).send_form(form),
+            None => self.agent.get(url).set("Cookie", &cookie).call(),
+        };
+
+        match response {
+            Ok(r) => Ok(r.into_string()?),
+            Err(ureq::Error::Status(code, r)) => {
+                let body = r.into_string().unwrap_or_default();
+                Err(AocError::Other(format!(
+                    "{url} returned {code}: {}",
+                    body.trim()
+                )))
+            }
+            Err(e) => Err(AocError::Other(format!("Unable to reach {url}: {e}"))),
+        }
+    }
+
+    pub fn input(&self, day: u8) -> Result<String, AocError> {
+        self.request(&self.url(day, "/input"), None)
+    }
+
+    // Returns the page the site answers with
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<String, AocError> {
+        let level = part.to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+        self.request(&self.url(day, "/answer"), Some(&form))
+    }
+}
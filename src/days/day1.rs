@@ -0,0 +1,35 @@
+use crate::{error::AocError, iter::IterExt};
+
+pub fn parse(input: &str) -> Result<Vec<Vec<i32>>, AocError> {
+    input
+        .split("\n\n")
+        .map(|g| {
+            g.lines()
+                .map(|l| {
+                    l.trim()
+                        .parse::<i32>()
+                        .map_err(|_| AocError::parse(input, l, "Expected a number"))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn group_sums(groups: &[Vec<i32>]) -> impl Iterator<Item = i32> + '_ {
+    groups.iter().map(|g| g.iter().sum::<i32>())
+}
+
+pub fn part1(groups: &[Vec<i32>]) -> Result<i32, AocError> {
+    group_sums(groups)
+        .max()
+        .ok_or(AocError::NoSolution(String::from("No elves")))
+}
+
+pub fn part2(groups: &[Vec<i32>]) -> Result<i32, AocError> {
+    let top = group_sums(groups).top_k(3);
+    if top.len() < 3 {
+        return Err(AocError::NoSolution(String::from("Fewer than three elves")));
+    }
+
+    Ok(top.iter().sum::<i32>())
+}
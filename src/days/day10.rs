@@ -0,0 +1,291 @@
+use crate::{error::AocError, geometry::Grid2d, ocr};
+use std::fmt;
+
+const SCREEN_WIDTH: usize = 40;
+const SCREEN_HEIGHT: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Registers {
+    pub x: i32,
+}
+
+impl Default for Registers {
+    fn default() -> Registers {
+        Registers { x: 1 }
+    }
+}
+
+// Anything the Cpu can run. Further instruction sets implement this, the Cpu
+// does not need to know about them.
+pub trait Op: Sized {
+    // None if the line is not one of these instructions
+    fn parse(line: &str) -> Option<Self>;
+
+    fn cycles(&self) -> u32;
+
+    // Runs at the end of the instruction's last cycle
+    fn execute(&self, regs: &mut Registers);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl Op for Instruction {
+    fn parse(line: &str) -> Option<Instruction> {
+        match line.split_whitespace().collect::<Vec<&str>>()[..] {
+            ["noop"] => Some(Instruction::Noop),
+            ["addx", v] => v.parse().ok().map(Instruction::Addx),
+            _ => None,
+        }
+    }
+
+    fn cycles(&self) -> u32 {
+        match self {
+            Instruction::Noop => 1,
+            Instruction::Addx(_) => 2,
+        }
+    }
+
+    fn execute(&self, regs: &mut Registers) {
+        if let Instruction::Addx(v) = self {
+            regs.x += v;
+        }
+    }
+}
+
+// Sees the registers during every cycle, before the cycle's instruction
+// finishes. Cycles count from 1.
+pub trait Device {
+    fn tick(&mut self, cycle: u64, regs: &Registers);
+}
+
+impl<F: FnMut(u64, &Registers)> Device for F {
+    fn tick(&mut self, cycle: u64, regs: &Registers) {
+        self(cycle, regs)
+    }
+}
+
+pub struct Cpu<'a, I: Op> {
+    program: &'a [I],
+    pc: usize,
+    // Cycles already spent on the instruction at pc
+    busy: u32,
+    cycle: u64,
+    pub regs: Registers,
+}
+
+impl<'a, I: Op> Cpu<'a, I> {
+    pub fn new(program: &'a [I]) -> Cpu<'a, I> {
+        Cpu {
+            program,
+            pc: 0,
+            busy: 0,
+            cycle: 0,
+            regs: Registers::default(),
+        }
+    }
+
+    pub fn cycle(&self) -> u64 {
+        self.cycle
+    }
+
+    // Runs one cycle, false once the program has finished
+    pub fn step(&mut self, bus: &mut [&mut dyn Device]) -> bool {
+        let Some(op) = self.program.get(self.pc) else {
+            return false;
+        };
+
+        self.cycle += 1;
+        for device in bus.iter_mut() {
+            device.tick(self.cycle, &self.regs);
+        }
+
+        self.busy += 1;
+        if self.busy >= op.cycles() {
+            op.execute(&mut self.regs);
+            self.pc += 1;
+            self.busy = 0;
+        }
+        true
+    }
+
+    pub fn run(&mut self, bus: &mut [&mut dyn Device]) {
+        while self.step(bus) {}
+    }
+}
+
+// Sums cycle * x during cycles 20, 60, 100, ...
+#[derive(Default)]
+pub struct SignalStrength {
+    pub total: i64,
+}
+
+impl Device for SignalStrength {
+    fn tick(&mut self, cycle: u64, regs: &Registers) {
+        if cycle % 40 == 20 {
+            self.total += cycle as i64 * regs.x as i64;
+        }
+    }
+}
+
+// Draws one pixel per cycle, lit when the three pixel wide sprite centred on
+// x covers it
+pub struct Crt {
+    pixels: Grid2d<bool>,
+    width: usize,
+    height: usize,
+}
+
+impl Crt {
+    pub fn new(width: usize, height: usize) -> Crt {
+        Crt {
+            pixels: Grid2d::with_size(width, height, false),
+            width,
+            height,
+        }
+    }
+
+    pub fn pixels(&self) -> &Grid2d<bool> {
+        &self.pixels
+    }
+}
+
+impl Default for Crt {
+    fn default() -> Crt {
+        Crt::new(SCREEN_WIDTH, SCREEN_HEIGHT)
+    }
+}
+
+impl Device for Crt {
+    fn tick(&mut self, cycle: u64, regs: &Registers) {
+        // Wraps back to the top once the screen is full
+        let pos = (cycle as usize - 1) % (self.width * self.height);
+        let (col, row) = ((pos % self.width) as i32, (pos / self.width) as i32);
+
+        if let Ok(pixel) = self.pixels.index_mut(col, row) {
+            *pixel = (col - regs.x).abs() <= 1;
+        }
+    }
+}
+
+impl fmt::Display for Crt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height as i32 {
+            for x in 0..self.width as i32 {
+                let lit = *self.pixels.index(x, y).unwrap_or(&false);
+                write!(f, "{}", if lit { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn parse_program<I: Op>(input: &str) -> Result<Vec<I>, AocError> {
+    input
+        .lines()
+        .map(|l| I::parse(l).ok_or_else(|| AocError::parse(input, l, "Unknown instruction")))
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, AocError> {
+    parse_program(input)
+}
+
+pub fn part1(program: &[Instruction]) -> i64 {
+    let mut signal = SignalStrength::default();
+    Cpu::new(program).run(&mut [&mut signal]);
+    signal.total
+}
+
+// The letters on the screen
+pub fn part2(program: &[Instruction]) -> Result<String, AocError> {
+    let mut crt = Crt::default();
+    Cpu::new(program).run(&mut [&mut crt]);
+    ocr::decode(crt.pixels())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // An instruction set that extends the puzzle's one
+    enum Extended {
+        Base(Instruction),
+        Mulx(i32),
+    }
+
+    impl Op for Extended {
+        fn parse(line: &str) -> Option<Extended> {
+            match line.strip_prefix("mulx ") {
+                Some(v) => v.parse().ok().map(Extended::Mulx),
+                None => Instruction::parse(line).map(Extended::Base),
+            }
+        }
+
+        fn cycles(&self) -> u32 {
+            match self {
+                Extended::Base(i) => i.cycles(),
+                Extended::Mulx(_) => 3,
+            }
+        }
+
+        fn execute(&self, regs: &mut Registers) {
+            match self {
+                Extended::Base(i) => i.execute(regs),
+                Extended::Mulx(v) => regs.x *= v,
+            }
+        }
+    }
+
+    #[test]
+    fn example_screen() {
+        // The example does not draw letters, so only the screen is compared
+        let program = parse(include_str!("../../fixtures/day10.txt")).unwrap();
+        let mut crt = Crt::default();
+        Cpu::new(&program).run(&mut [&mut crt]);
+        assert_eq!(
+            crt.to_string(),
+            "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+"
+        );
+
+        let e = part2(&program).unwrap_err();
+        assert!(e.to_string().starts_with("Unknown glyphs"), "{e}");
+    }
+
+    #[test]
+    fn small_program() {
+        let program = parse("noop\naddx 3\naddx -5\n").unwrap();
+        let mut seen = Vec::new();
+        let mut cpu = Cpu::new(&program);
+        cpu.run(&mut [&mut |cycle, regs: &Registers| seen.push((cycle, regs.x))]);
+
+        assert_eq!(seen, vec![(1, 1), (2, 1), (3, 1), (4, 4), (5, 4)]);
+        assert_eq!(cpu.regs.x, -1);
+        assert_eq!(cpu.cycle(), 5);
+        assert!(!cpu.step(&mut []));
+    }
+
+    #[test]
+    fn extended_opcodes() {
+        let program = parse_program::<Extended>("addx 2\nmulx 4\nnoop\n").unwrap();
+        let mut cpu = Cpu::new(&program);
+        cpu.run(&mut []);
+        assert_eq!((cpu.regs.x, cpu.cycle()), (12, 6));
+
+        let e = parse("noop\nmulx 4\n").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Parse error on line 2: Unknown instruction in \"mulx 4\""
+        );
+    }
+}
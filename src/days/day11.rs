@@ -0,0 +1,518 @@
+use crate::{
+    error::AocError,
+    expr::{BinOp, Expr},
+    iter::IterExt,
+};
+use std::{collections::VecDeque, io::Write};
+
+// Items keep their id wherever they are thrown
+pub type ItemId = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Item {
+    pub id: ItemId,
+    pub worry: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: VecDeque<Item>,
+    test_div: i64,
+    true_monkey: usize,
+    false_monkey: usize,
+    operation: Expr,
+}
+
+impl Monkey {
+    pub fn items(&self) -> &VecDeque<Item> {
+        &self.items
+    }
+
+    fn target(&self, worry: i64) -> usize {
+        if worry % self.test_div == 0 {
+            self.true_monkey
+        } else {
+            self.false_monkey
+        }
+    }
+}
+
+// What happens to an item's worry level after a monkey inspects it
+pub enum Relief {
+    None,
+    DivideBy(i64),
+    Custom(Box<dyn Fn(i64) -> i64>),
+}
+
+impl Relief {
+    fn apply(&self, worry: i64) -> i64 {
+        match self {
+            Relief::None => worry,
+            Relief::DivideBy(d) => worry / d,
+            Relief::Custom(f) => f(worry),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    Silent,
+    // The items each monkey holds after every round
+    Rounds,
+    // Every inspection, in the puzzle's words
+    Narrate,
+}
+
+pub struct Simulation {
+    rounds: usize,
+    relief: Relief,
+    // Worry levels are kept below this, see common_modulus()
+    modulus: Option<i64>,
+    verbosity: Verbosity,
+    track_routes: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    // How often each monkey inspected an item, in monkey order
+    pub inspections: Vec<usize>,
+    // For each item, every monkey that held it in turn, starting with the
+    // one it started with. Empty unless the simulation tracks routes.
+    pub routes: Vec<Vec<usize>>,
+}
+
+impl Report {
+    // The two highest inspection counts multiplied together
+    pub fn monkey_business(&self) -> Result<usize, AocError> {
+        match self.inspections.iter().copied().top_k(2)[..] {
+            [a, b] => Ok(a * b),
+            _ => Err(AocError::NoSolution(String::from("Fewer than two monkeys"))),
+        }
+    }
+
+    pub fn route(&self, item: ItemId) -> Option<&[usize]> {
+        self.routes.get(item).map(|r| r.as_slice())
+    }
+
+    // throws[a][b] is how often any item went from monkey a to monkey b
+    pub fn throws(&self) -> Vec<Vec<usize>> {
+        let n = self.inspections.len();
+        let mut throws = vec![vec![0; n]; n];
+        for route in self.routes.iter() {
+            for [a, b] in route.iter().windows_array::<2>() {
+                throws[*a][*b] += 1;
+            }
+        }
+        throws
+    }
+}
+
+// Divisibility by every monkey's divisor survives reducing the worry modulo
+// their least common multiple
+pub fn common_modulus(monkeys: &[Monkey]) -> i64 {
+    fn gcd(a: i64, b: i64) -> i64 {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    monkeys
+        .iter()
+        .fold(1, |lcm, m| lcm / gcd(lcm, m.test_div) * m.test_div)
+}
+
+// The puzzle's wording for the usual operations
+fn describe(operation: &Expr, worry: i64) -> String {
+    let operand = |e: &Expr| match e {
+        Expr::Old => Some(String::from("itself")),
+        Expr::Literal(n) => Some(n.to_string()),
+        Expr::Binary(..) => None,
+    };
+
+    if let Expr::Binary(lhs, op, rhs) = operation {
+        if let (Expr::Old, Some(rhs)) = (lhs.as_ref(), operand(rhs)) {
+            let verb = match op {
+                BinOp::Add => "increases by",
+                BinOp::Sub => "decreases by",
+                BinOp::Mul => "is multiplied by",
+                BinOp::Div => "is divided by",
+            };
+            return format!("Worry level {verb} {rhs} to {worry}.");
+        }
+    }
+    format!("Worry level becomes {operation} = {worry}.")
+}
+
+impl Simulation {
+    pub fn new(rounds: usize) -> Simulation {
+        Simulation {
+            rounds,
+            relief: Relief::None,
+            modulus: None,
+            verbosity: Verbosity::Silent,
+            track_routes: false,
+        }
+    }
+
+    pub fn relief(mut self, relief: Relief) -> Simulation {
+        self.relief = relief;
+        self
+    }
+
+    pub fn modulus(mut self, modulus: i64) -> Simulation {
+        self.modulus = Some(modulus);
+        self
+    }
+
+    pub fn verbosity(mut self, verbosity: Verbosity) -> Simulation {
+        self.verbosity = verbosity;
+        self
+    }
+
+    // Routes grow with every throw, so they are only kept when asked for
+    pub fn track_routes(mut self) -> Simulation {
+        self.track_routes = true;
+        self
+    }
+
+    fn inspect(&self, monkey: &Monkey, item: i64, out: &mut dyn Write) -> Result<i64, AocError> {
+        let narrate = self.verbosity >= Verbosity::Narrate;
+        let mut worry = monkey.operation.eval(item)?;
+        if narrate {
+            writeln!(
+                out,
+                "  Monkey inspects an item with a worry level of {item}."
+            )?;
+            writeln!(out, "    {}", describe(&monkey.operation, worry))?;
+        }
+
+        worry = self.relief.apply(worry);
+        if narrate {
+            match self.relief {
+                Relief::None => {}
+                Relief::DivideBy(d) => writeln!(
+                    out,
+                    "    Monkey gets bored with item. Worry level is divided by {d} to {worry}."
+                )?,
+                Relief::Custom(_) => writeln!(
+                    out,
+                    "    Monkey gets bored with item. Worry level is now {worry}."
+                )?,
+            }
+        }
+
+        if let Some(m) = self.modulus {
+            worry %= m;
+        }
+        Ok(worry)
+    }
+
+    fn turn(
+        &self,
+        monkeys: &mut [Monkey],
+        i: usize,
+        routes: &mut [Vec<usize>],
+        out: &mut dyn Write,
+    ) -> Result<usize, AocError> {
+        if self.verbosity >= Verbosity::Narrate {
+            writeln!(out, "Monkey {i}:")?;
+        }
+
+        // Only the items held at the start of the turn, in case a monkey
+        // throws to itself
+        let count = monkeys[i].items.len();
+        for _ in 0..count {
+            let Some(mut item) = monkeys[i].items.pop_front() else {
+                break;
+            };
+            item.worry = self.inspect(&monkeys[i], item.worry, out)?;
+            let target = monkeys[i].target(item.worry);
+
+            if self.verbosity >= Verbosity::Narrate {
+                let not = if target == monkeys[i].true_monkey {
+                    ""
+                } else {
+                    "not "
+                };
+                writeln!(
+                    out,
+                    "    Current worry level is {not}divisible by {}.",
+                    monkeys[i].test_div
+                )?;
+                writeln!(
+                    out,
+                    "    Item with worry level {} is thrown to monkey {target}.",
+                    item.worry
+                )?;
+            }
+
+            if self.track_routes {
+                routes
+                    .get_mut(item.id)
+                    .ok_or(AocError::Other(format!("No route for item {}", item.id)))?
+                    .push(target);
+            }
+            monkeys[target].items.push_back(item);
+        }
+
+        Ok(count)
+    }
+
+    pub fn run<W: Write>(&self, monkeys: &[Monkey], out: &mut W) -> Result<Report, AocError> {
+        // (x mod m) / 3 is not x / 3 mod m, so relief would change later tests
+        if self.modulus.is_some() && !matches!(self.relief, Relief::None) {
+            return Err(AocError::Other(String::from(
+                "Worry levels cannot be both relieved and reduced by a modulus",
+            )));
+        }
+        if matches!(self.relief, Relief::DivideBy(0)) || self.modulus == Some(0) {
+            return Err(AocError::Other(String::from(
+                "Worry levels cannot be divided or reduced by 0",
+            )));
+        }
+
+        let mut monkeys = monkeys.to_vec();
+        let mut inspections = vec![0; monkeys.len()];
+        let mut routes = Vec::new();
+        if self.track_routes {
+            for (i, m) in monkeys.iter().enumerate() {
+                for item in m.items.iter() {
+                    if routes.len() <= item.id {
+                        routes.resize(item.id + 1, Vec::new());
+                    }
+                    if !routes[item.id].is_empty() {
+                        return Err(AocError::Other(format!(
+                            "Item id {} is used more than once",
+                            item.id
+                        )));
+                    }
+                    routes[item.id].push(i);
+                }
+            }
+        }
+
+        for round in 1..=self.rounds {
+            for (i, count) in inspections.iter_mut().enumerate() {
+                *count += self.turn(&mut monkeys, i, &mut routes, out)?;
+            }
+
+            if self.verbosity >= Verbosity::Rounds {
+                writeln!(
+                    out,
+                    "After round {round}, the monkeys are holding items with these worry levels:"
+                )?;
+                for (i, m) in monkeys.iter().enumerate() {
+                    let items = m
+                        .items
+                        .iter()
+                        .map(|item| item.worry.to_string())
+                        .collect::<Vec<_>>();
+                    writeln!(out, "Monkey {i}: {}", items.join(", "))?;
+                }
+                writeln!(out)?;
+            }
+        }
+
+        Ok(Report {
+            inspections,
+            routes,
+        })
+    }
+}
+
+pub fn part1_simulation() -> Simulation {
+    Simulation::new(20).relief(Relief::DivideBy(3))
+}
+
+// Without relief the worry levels have to be kept in check another way
+pub fn part2_simulation(monkeys: &[Monkey]) -> Simulation {
+    Simulation::new(10000).modulus(common_modulus(monkeys))
+}
+
+// Both answers, narrating the first simulation at the given verbosity
+pub fn answers<W: Write>(
+    monkeys: &[Monkey],
+    verbosity: Verbosity,
+    out: &mut W,
+) -> Result<(usize, usize), AocError> {
+    let part1 = part1_simulation().verbosity(verbosity).run(monkeys, out)?;
+    let part2 = part2_simulation(monkeys).run(monkeys, out)?;
+
+    Ok((part1.monkey_business()?, part2.monkey_business()?))
+}
+
+// raw is one monkey's block out of input, its items are numbered from
+// first_id on
+fn parse_monkey(input: &str, raw: &str, first_id: ItemId) -> Result<Monkey, AocError> {
+    let lines = raw.lines().collect::<Vec<&str>>();
+    if lines.len() < 6 {
+        return Err(AocError::parse(input, raw, "Expected six lines per monkey"));
+    }
+
+    let field = |i: usize, prefix: &str| {
+        lines[i]
+            .trim()
+            .strip_prefix(prefix)
+            .map(|f| f.trim())
+            .ok_or_else(|| AocError::parse(input, lines[i], &format!("Expected {prefix}")))
+    };
+    let number = |i: usize, prefix: &str| {
+        field(i, prefix)?
+            .parse::<i64>()
+            .map_err(|_| AocError::parse(input, lines[i], "Expected a number"))
+    };
+
+    // Parse "Starting items: ##, ##, ##..."
+    let items = field(1, "Starting items:")?
+        .split(',')
+        .filter(|s| !s.trim().is_empty())
+        .map(|s| s.trim().parse::<i64>())
+        .enumerate()
+        .map(|(i, worry)| {
+            worry.map(|worry| Item {
+                id: first_id + i,
+                worry,
+            })
+        })
+        .collect::<Result<VecDeque<Item>, _>>()
+        .map_err(|_| AocError::parse(input, lines[1], "Expected a list of numbers"))?;
+
+    // Parse 'Operation: new = <expression in old>'
+    let operation = Expr::parse_in(input, field(2, "Operation: new =")?)?;
+
+    // Test: divisible by #
+    let test_div = number(3, "Test: divisible by")?;
+    if test_div <= 0 {
+        return Err(AocError::parse(input, lines[3], "Divisor must be positive"));
+    }
+
+    // If true: throw to monkey #
+    let true_monkey = number(4, "If true: throw to monkey")? as usize;
+
+    // If false: throw to monkey #
+    let false_monkey = number(5, "If false: throw to monkey")? as usize;
+
+    Ok(Monkey {
+        items,
+        test_div,
+        true_monkey,
+        false_monkey,
+        operation,
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<Monkey>, AocError> {
+    let raw = input.split("\n\n").collect::<Vec<&str>>();
+
+    // Items are numbered across all monkeys, in the order they are listed
+    let mut monkeys = Vec::new();
+    let mut next_id = 0;
+    for m in raw.iter() {
+        let monkey = parse_monkey(input, m, next_id)?;
+        next_id += monkey.items.len();
+        monkeys.push(monkey);
+    }
+
+    for (m, raw) in monkeys.iter().zip(raw) {
+        if m.true_monkey.max(m.false_monkey) >= monkeys.len() {
+            return Err(AocError::parse(
+                input,
+                raw,
+                "Throws to a monkey that does not exist",
+            ));
+        }
+    }
+
+    Ok(monkeys)
+}
+
+pub fn part1(monkeys: &[Monkey]) -> Result<usize, AocError> {
+    part1_simulation()
+        .run(monkeys, &mut std::io::sink())?
+        .monkey_business()
+}
+
+pub fn part2(monkeys: &[Monkey]) -> Result<usize, AocError> {
+    part2_simulation(monkeys)
+        .run(monkeys, &mut std::io::sink())?
+        .monkey_business()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/day11.txt");
+
+    #[test]
+    fn narration() {
+        let monkeys = parse(EXAMPLE).unwrap();
+        let mut out = Vec::new();
+        let report = Simulation::new(1)
+            .relief(Relief::DivideBy(3))
+            .verbosity(Verbosity::Narrate)
+            .run(&monkeys, &mut out)
+            .unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.starts_with(
+            "Monkey 0:
+  Monkey inspects an item with a worry level of 79.
+    Worry level is multiplied by 19 to 1501.
+    Monkey gets bored with item. Worry level is divided by 3 to 500.
+    Current worry level is not divisible by 23.
+    Item with worry level 500 is thrown to monkey 3.
+"
+        ));
+        assert!(out.contains("Worry level is multiplied by itself to 3600."));
+        assert!(out.contains("Monkey 1: 2080, 25, 167, 207, 401, 1046\n"));
+        assert_eq!(report.inspections, vec![2, 4, 3, 5]);
+    }
+
+    #[test]
+    fn both_answers() {
+        let monkeys = parse(EXAMPLE).unwrap();
+        let answers = answers(&monkeys, Verbosity::Silent, &mut Vec::new()).unwrap();
+        assert_eq!(answers, (10605, 2713310158));
+        assert_eq!(common_modulus(&monkeys), 96577);
+
+        let relieved = part1_simulation().modulus(common_modulus(&monkeys));
+        assert!(relieved.run(&monkeys, &mut std::io::sink()).is_err());
+
+        let by_zero = Simulation::new(1).relief(Relief::DivideBy(0));
+        assert!(by_zero.run(&monkeys, &mut std::io::sink()).is_err());
+        let mod_zero = Simulation::new(1).modulus(0);
+        assert!(mod_zero.run(&monkeys, &mut std::io::sink()).is_err());
+    }
+
+    #[test]
+    fn routes() {
+        let monkeys = parse(EXAMPLE).unwrap();
+        assert_eq!(monkeys[1].items()[0], Item { id: 2, worry: 54 });
+
+        let report = part1_simulation()
+            .track_routes()
+            .run(&monkeys, &mut std::io::sink())
+            .unwrap();
+        assert_eq!(report.routes.len(), 10);
+
+        // 79 goes to monkey 3, then on to monkey 1 in the same round
+        assert_eq!(report.route(0).unwrap()[..3], [0, 3, 1]);
+
+        // Every inspection is one throw
+        let throws = report.throws();
+        let thrown_by = throws
+            .iter()
+            .map(|t| t.iter().sum())
+            .collect::<Vec<usize>>();
+        assert_eq!(thrown_by, report.inspections);
+
+        // Two items with the same id would share a route
+        let mut clash = monkeys.clone();
+        clash[0].items[0].id = 2;
+        let tracked = part1_simulation().track_routes();
+        assert!(tracked.run(&clash, &mut std::io::sink()).is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{
+    error::AocError,
+    geometry::{BoundingBox, Grid2d, Point},
+    search::{self, Found},
+};
+
+const NEIGHBOURS: [Point<i32>; 4] = [
+    Point { x: 0, y: -1 },
+    Point { x: 0, y: 1 },
+    Point { x: -1, y: 0 },
+    Point { x: 1, y: 0 },
+];
+
+// Heights 0 for a up to 25 for z. S is at height a and E at height z.
+pub struct HeightMap {
+    heights: Grid2d<u8>,
+    pub start: Point<i32>,
+    pub goal: Point<i32>,
+}
+
+impl HeightMap {
+    pub fn from(s: &str) -> Result<HeightMap, AocError> {
+        let width = s.lines().next().map_or(0, |l| l.len());
+        let height = s.lines().count();
+        if width == 0 {
+            return Err(AocError::parse(s, s, "No heights"));
+        }
+
+        let mut heights = Grid2d::with_size(width, height, 0);
+        let mut start = None;
+        let mut goal = None;
+
+        for (y, l) in s.lines().enumerate() {
+            if l.len() != width {
+                return Err(AocError::parse(s, l, "Rows must be the same length"));
+            }
+
+            for (x, c) in l.chars().enumerate() {
+                let p = Point::new(x as i32, y as i32);
+                let c = match c {
+                    'S' if start.replace(p).is_none() => 'a',
+                    'E' if goal.replace(p).is_none() => 'z',
+                    'S' | 'E' => {
+                        return Err(AocError::parse(s, l, &format!("Expected exactly one {c}")))
+                    }
+                    'a'..='z' => c,
+                    _ => return Err(AocError::parse(s, l, "Expected heights a-z, S or E")),
+                };
+                *heights.index_mut(p.x, p.y)? = c as u8 - b'a';
+            }
+        }
+
+        match (start, goal) {
+            (Some(start), Some(goal)) => Ok(HeightMap {
+                heights,
+                start,
+                goal,
+            }),
+            (None, _) => Err(AocError::parse(s, s, "Expected exactly one S")),
+            (_, None) => Err(AocError::parse(s, s, "Expected exactly one E")),
+        }
+    }
+
+    pub fn height(&self, p: Point<i32>) -> Option<u8> {
+        self.heights.index(p.x, p.y).ok().copied()
+    }
+
+    pub fn heights(&self) -> &Grid2d<u8> {
+        &self.heights
+    }
+
+    // The map as it was read, with S and E
+    pub fn render(&self) -> Grid2d<char> {
+        let bounds = self.heights.bounds();
+        let mut grid = Grid2d::with_coordinates(bounds, '.');
+        for y in bounds.tl.y..bounds.br.y {
+            for x in bounds.tl.x..bounds.br.x {
+                let h = self.height(Point::new(x, y)).unwrap_or_default();
+                grid.set_or_insert(x, y, (b'a' + h) as char);
+            }
+        }
+        grid.set_or_insert(self.start.x, self.start.y, 'S');
+        grid.set_or_insert(self.goal.x, self.goal.y, 'E');
+        grid
+    }
+
+    // Squares next to p that are at most one step up
+    fn climbs(&self, p: Point<i32>) -> impl Iterator<Item = Point<i32>> + '_ {
+        let h = self.height(p).unwrap_or_default();
+        NEIGHBOURS
+            .iter()
+            .map(move |n| p + *n)
+            .filter(move |n| self.height(*n).is_some_and(|nh| nh <= h + 1))
+    }
+
+    // The same moves walked backwards, at most one step down
+    fn descents(&self, p: Point<i32>) -> impl Iterator<Item = Point<i32>> + '_ {
+        let h = self.height(p).unwrap_or_default();
+        NEIGHBOURS
+            .iter()
+            .map(move |n| p + *n)
+            .filter(move |n| self.height(*n).is_some_and(|nh| nh + 1 >= h))
+    }
+
+    pub fn shortest_path(&self) -> Option<Found<Point<i32>>> {
+        search::bfs(self.start, |p| self.climbs(*p), |p| *p == self.goal)
+    }
+
+    // From the closest square at height a, found by searching back from E
+    pub fn shortest_hike(&self) -> Option<Found<Point<i32>>> {
+        let mut found = search::bfs(
+            self.goal,
+            |p| self.descents(*p),
+            |p| self.height(*p) == Some(0),
+        )?;
+        found.path.reverse();
+        Some(found)
+    }
+}
+
+pub fn parse(input: &str) -> Result<HeightMap, AocError> {
+    HeightMap::from(input)
+}
+
+fn reached(found: Option<Found<Point<i32>>>) -> Result<u64, AocError> {
+    found
+        .map(|f| f.cost)
+        .ok_or(AocError::NoSolution(String::from(
+            "The goal cannot be reached",
+        )))
+}
+
+pub fn part1(map: &HeightMap) -> Result<u64, AocError> {
+    reached(map.shortest_path())
+}
+
+pub fn part2(map: &HeightMap) -> Result<u64, AocError> {
+    reached(map.shortest_hike())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/day12.txt");
+
+    #[test]
+    fn paths() {
+        let map = parse(EXAMPLE).unwrap();
+        let found = map.shortest_path().unwrap();
+        assert_eq!(found.cost, 31);
+        assert_eq!(found.path.first(), Some(&map.start));
+        assert_eq!(found.path.last(), Some(&map.goal));
+
+        let hike = map.shortest_hike().unwrap();
+        assert_eq!(hike.cost, 29);
+        assert_eq!(map.height(hike.path[0]), Some(0));
+        assert_eq!(hike.path.last(), Some(&map.goal));
+        assert_eq!(format!("{:?}", map.render()), EXAMPLE);
+    }
+
+    #[test]
+    fn unreachable() {
+        let map = parse("SbcE\n").unwrap();
+        assert!(part1(&map).is_err());
+        assert!(parse("SaE\nSaa\n").is_err());
+        assert!(parse("Saa\n").is_err());
+    }
+}
@@ -0,0 +1,234 @@
+use crate::error::AocError;
+use std::{cmp::Ordering, fmt};
+
+#[derive(Debug, Clone)]
+pub enum Packet {
+    Int(u32),
+    List(Vec<Packet>),
+}
+
+impl Packet {
+    pub fn from(s: &str) -> Result<Packet, AocError> {
+        Packet::parse_in(s, s)
+    }
+
+    pub fn parse_in(input: &str, s: &str) -> Result<Packet, AocError> {
+        let s = s.trim();
+        let mut parser = Parser { input, s, pos: 0 };
+        if !s.starts_with('[') {
+            return Err(parser.error("Expected a packet like [1,[2]]"));
+        }
+
+        let packet = parser.packet()?;
+        if parser.pos < s.len() {
+            return Err(parser.error("Expected the packet to end"));
+        }
+        Ok(packet)
+    }
+
+    pub fn divider(n: u32) -> Packet {
+        Packet::List(vec![Packet::List(vec![Packet::Int(n)])])
+    }
+}
+
+// Integers compare as numbers and lists element by element, the shorter list
+// first if one runs out. An integer next to a list is compared as a list
+// holding just that integer.
+impl Ord for Packet {
+    fn cmp(&self, other: &Packet) -> Ordering {
+        match (self, other) {
+            (Packet::Int(a), Packet::Int(b)) => a.cmp(b),
+            (Packet::List(a), Packet::List(b)) => a.cmp(b),
+            (Packet::Int(a), Packet::List(b)) => [Packet::Int(*a)][..].cmp(b),
+            (Packet::List(a), Packet::Int(b)) => a[..].cmp(&[Packet::Int(*b)]),
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Packet) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Equal as far as the ordering goes, so 1 and [[1]] are the same packet
+impl PartialEq for Packet {
+    fn eq(&self, other: &Packet) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Packet {}
+
+// The same format the packets are read in
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Int(n) => write!(f, "{n}"),
+            Packet::List(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+// Recursive descent over the bytes of one packet
+struct Parser<'a> {
+    input: &'a str,
+    s: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.s.as_bytes().get(self.pos).copied()
+    }
+
+    fn error(&self, reason: &str) -> AocError {
+        let at = self.s.get(self.pos..).filter(|r| !r.is_empty());
+        AocError::parse(self.input, at.unwrap_or(self.s), reason)
+    }
+
+    // 19 or [...]
+    fn packet(&mut self) -> Result<Packet, AocError> {
+        match self.peek() {
+            Some(b'[') => self.list(),
+            Some(b'0'..=b'9') => self.int(),
+            _ => Err(self.error("Expected a number or [")),
+        }
+    }
+
+    fn int(&mut self) -> Result<Packet, AocError> {
+        let len = self.s[self.pos..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.s.len() - self.pos);
+        let n = self.s[self.pos..self.pos + len]
+            .parse()
+            .map_err(|_| self.error("Number is too large"))?;
+        self.pos += len;
+        Ok(Packet::Int(n))
+    }
+
+    // [], [a] or [a,b,...]
+    fn list(&mut self) -> Result<Packet, AocError> {
+        self.pos += 1;
+        let mut items = Vec::new();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Packet::List(items));
+        }
+
+        loop {
+            items.push(self.packet()?);
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Packet::List(items));
+                }
+                _ => return Err(self.error("Expected , or ]")),
+            }
+        }
+    }
+}
+
+// Pairs of packets separated by blank lines
+pub fn parse(input: &str) -> Result<Vec<(Packet, Packet)>, AocError> {
+    let mut pairs = Vec::new();
+    let mut group = Vec::new();
+
+    for l in input.lines().chain([""]) {
+        if !l.trim().is_empty() {
+            group.push(l);
+            continue;
+        }
+
+        match group[..] {
+            [] => {}
+            [left, right] => pairs.push((
+                Packet::parse_in(input, left)?,
+                Packet::parse_in(input, right)?,
+            )),
+            _ => {
+                return Err(AocError::parse(
+                    input,
+                    group[0],
+                    "Expected a pair of packets",
+                ))
+            }
+        }
+        group.clear();
+    }
+
+    Ok(pairs)
+}
+
+pub fn part1(pairs: &[(Packet, Packet)]) -> usize {
+    (1..)
+        .zip(pairs)
+        .filter(|(_, (left, right))| left < right)
+        .map(|(i, _)| i)
+        .sum()
+}
+
+pub fn part2(pairs: &[(Packet, Packet)]) -> usize {
+    let dividers = [Packet::divider(2), Packet::divider(6)];
+    let mut packets = pairs
+        .iter()
+        .flat_map(|(left, right)| [left.clone(), right.clone()])
+        .collect::<Vec<Packet>>();
+    packets.extend(dividers.iter().cloned());
+    packets.sort();
+
+    dividers
+        .iter()
+        .map(|d| packets.binary_search(d).unwrap_or_else(|i| i) + 1)
+        .product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/day13.txt");
+
+    #[test]
+    fn ordering() {
+        let pairs = parse(EXAMPLE).unwrap();
+        let in_order = pairs.iter().map(|(l, r)| l < r).collect::<Vec<bool>>();
+        assert_eq!(
+            in_order,
+            [true, true, false, true, false, true, false, false]
+        );
+
+        let p = |s| Packet::from(s).unwrap();
+        assert_eq!(p("[[1],[2,3,4]]").cmp(&p("[[1],4]")), Ordering::Less);
+        assert_eq!(p("[[[]]]").cmp(&p("[[]]")), Ordering::Greater);
+        assert_eq!(p("[3]"), p("[[[3]]]"));
+
+        for l in EXAMPLE.lines().filter(|l| !l.is_empty()) {
+            assert_eq!(p(l).to_string(), l);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert!(Packet::from("[1,2").is_err());
+        assert!(Packet::from("[1,,2]").is_err());
+        assert!(Packet::from("[1]]").is_err());
+        assert!(Packet::from("3").is_err());
+        assert!(Packet::from("[a]").is_err());
+        assert!(Packet::from("[99999999999]").is_err());
+
+        let e = parse("[1]\n[2]\n\n[3]\n").unwrap_err();
+        assert!(matches!(e, AocError::Parse { line: 4, .. }), "{e}");
+        assert!(parse("[1]\n[2]\n[3]\n").is_err());
+    }
+}
@@ -0,0 +1,410 @@
+use crate::error::AocError;
+use std::fmt::Debug;
+
+pub trait BoundingBox {
+    fn bounds(&self) -> (Point, Point);
+
+    fn merge_bounds<T>(&self, other: T) -> (Point, Point)
+    where
+        T: BoundingBox,
+    {
+        let mine = self.bounds();
+        let other = other.bounds();
+        let x = [mine.0.x, mine.1.x, other.0.x, other.1.x];
+        let y = [mine.0.y, mine.1.y, other.0.y, other.1.y];
+
+        (
+            Point::new(*x.iter().min().unwrap(), *y.iter().min().unwrap()),
+            Point::new(*x.iter().max().unwrap(), *y.iter().max().unwrap()),
+        )
+    }
+
+    fn in_bounds(&self, p: &Point) -> bool {
+        let bounds = self.bounds();
+
+        p.x >= bounds.0.x && p.x <= bounds.1.x && p.y >= bounds.0.y && p.y <= bounds.1.y
+    }
+}
+
+impl BoundingBox for (Point, Point) {
+    fn bounds(&self) -> (Point, Point) {
+        (self.0, self.1)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    x: usize,
+    y: usize,
+}
+
+// ###,###
+fn parse_point(input: &str, s: &str) -> Result<Point, AocError> {
+    s.split_once(',')
+        .and_then(|(x, y)| Some(Point::new(x.trim().parse().ok()?, y.trim().parse().ok()?)))
+        .ok_or_else(|| AocError::parse(input, s, "Expected a point like 498,4"))
+}
+
+// ###,### -> ###,### -> ###,###
+fn parse_structure(input: &str, s: &str) -> Result<Structure, AocError> {
+    let vals = s.split("->").map(|a| a.trim()).collect::<Vec<&str>>();
+    if vals.len() < 2 {
+        return Err(AocError::parse(input, s, "Expected at least two points"));
+    }
+
+    let mut lines = Vec::new();
+    for v in vals.windows(2) {
+        let line = Line::new(parse_point(input, v[0])?, parse_point(input, v[1])?);
+        if line.start.x != line.end.x && line.start.y != line.end.y {
+            return Err(AocError::parse(
+                input,
+                v[1],
+                "Lines must be horizontal or vertical",
+            ));
+        }
+        lines.push(line);
+    }
+
+    Ok(Structure { lines })
+}
+
+impl Point {
+    pub fn from(s: &str) -> Result<Point, AocError> {
+        parse_point(s, s)
+    }
+
+    pub fn new(x: usize, y: usize) -> Point {
+        Point { x, y }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Line {
+    start: Point,
+    end: Point,
+}
+
+impl Line {
+    pub fn new(start: Point, end: Point) -> Line {
+        Line { start, end }
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x && self.start.y != self.end.y
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.start.x != self.end.x && self.start.y == self.end.y
+    }
+
+    // Return true if point is occupied by this line
+    pub fn occupied(&self, p: &Point) -> bool {
+        if self.is_horizontal() {
+            let max = self.start.x.max(self.end.x);
+            let min = self.start.x.min(self.end.x);
+            p.y == self.start.y && min <= p.x && p.x <= max
+        } else {
+            let max = self.start.y.max(self.end.y);
+            let min = self.start.y.min(self.end.y);
+            p.x == self.start.x && min <= p.y && p.y <= max
+        }
+    }
+}
+
+impl BoundingBox for Line {
+    // (top left e.g. [min_x, miny], bottom right)
+    fn bounds(&self) -> (Point, Point) {
+        let tl = Point::new(self.start.x.min(self.end.x), self.start.y.min(self.end.y));
+        let br = Point::new(self.start.x.max(self.end.x), self.start.y.max(self.end.y));
+
+        (tl, br)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Structure {
+    lines: Vec<Line>,
+}
+
+impl Structure {
+    pub fn from(s: &str) -> Result<Structure, AocError> {
+        parse_structure(s, s)
+    }
+
+    // Return true if point is occupied by this structure
+    pub fn occupied(&self, p: &Point) -> bool {
+        for line in self.lines.iter() {
+            if line.occupied(p) {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+impl BoundingBox for Structure {
+    // (top left e.g. [min_x, miny], bottom right)
+    fn bounds(&self) -> (Point, Point) {
+        let mut b = self.lines[0].bounds();
+
+        for line in self.lines.iter().skip(1) {
+            b = b.merge_bounds(line.bounds());
+        }
+
+        b
+    }
+}
+
+#[derive(Clone)]
+pub struct Cave {
+    rocks: Vec<Structure>,
+    sand_entry: Point,
+    sand: Vec<Point>,
+    has_floor: bool,
+}
+
+impl Cave {
+    pub fn from(s: &str) -> Result<Cave, AocError> {
+        let rocks = s
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| parse_structure(s, l))
+            .collect::<Result<Vec<Structure>, AocError>>()?;
+        if rocks.is_empty() {
+            return Err(AocError::parse(s, s, "No rock structures"));
+        }
+
+        Ok(Cave {
+            rocks,
+            sand_entry: Point::new(500, 0),
+            sand: Vec::new(),
+            has_floor: false,
+        })
+    }
+
+    pub fn add_floor(&mut self) {
+        self.has_floor = true;
+    }
+
+    pub fn width(&self) -> usize {
+        let bounds = self.bounds();
+        bounds.1.x - bounds.0.x
+    }
+
+    pub fn depth(&self) -> usize {
+        self.bounds().1.y
+    }
+
+    // Return true if point is occupied by any structure
+    pub fn rock(&self, p: &Point) -> bool {
+        for s in self.rocks.iter() {
+            if s.occupied(p) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    pub fn sand(&self, p: &Point) -> bool {
+        for s in self.sand.iter() {
+            if s.eq(p) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    pub fn occupied(&self, p: &Point) -> bool {
+        let at_floor = if self.has_floor {
+            p.y >= self.depth() + 2
+        } else {
+            false
+        };
+
+        self.sand(p) || self.rock(p) || at_floor
+    }
+
+    fn do_drop_sand(&self, p: Point) -> Option<Point> {
+        if !self.in_bounds(&p) && !self.has_floor {
+            return None;
+        }
+
+        let mut lookahead = p;
+        lookahead.y += 1;
+
+        if !self.occupied(&lookahead) {
+            return self.do_drop_sand(lookahead);
+        }
+
+        lookahead.x -= 1;
+
+        if !self.occupied(&lookahead) {
+            return self.do_drop_sand(lookahead);
+        }
+
+        lookahead.x += 2;
+
+        if !self.occupied(&lookahead) {
+            return self.do_drop_sand(lookahead);
+        }
+
+        if p == self.sand_entry {
+            return None;
+        }
+
+        Some(p)
+    }
+
+    // Return true to sand stayed in cave
+    pub fn drop_sand(&mut self) -> bool {
+        if let Some(p) = self.do_drop_sand(self.sand_entry) {
+            self.sand.push(p);
+            return true;
+        }
+        false
+    }
+
+    pub fn fill_with_sand(&mut self) -> usize {
+        while self.drop_sand() {}
+
+        let adder = if self.has_floor { 1 } else { 0 };
+        self.sand.len() + adder
+    }
+
+    pub fn clear_sand(&mut self) {
+        self.sand.clear();
+    }
+}
+
+impl BoundingBox for Cave {
+    // (top left e.g. [min_x, miny], bottom right)
+    fn bounds(&self) -> (Point, Point) {
+        let mut b = self.rocks[0].bounds();
+
+        for rock in self.rocks.iter().skip(1) {
+            b = b.merge_bounds(rock.bounds());
+        }
+        b.0.y = 0;
+
+        b
+    }
+}
+
+impl Debug for Cave {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Print header
+        writeln!(f)?;
+        let bounds = self.bounds();
+        let width = ((self.depth() as f64).log(10.0).floor() as usize) + 3;
+
+        for y in bounds.0.y..bounds.1.y + 1 {
+            write!(f, "{:<width$}", y, width = width)?;
+            for x in bounds.0.x..bounds.1.x + 1 {
+                let mut c = '.';
+                if x == self.sand_entry.x && y == self.sand_entry.y {
+                    c = '+';
+                } else if self.rock(&Point::new(x, y)) {
+                    c = '#';
+                } else if self.sand(&Point::new(x, y)) {
+                    c = 'o';
+                }
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+
+        if self.has_floor {
+            for y in 0..2 {
+                write!(f, "{:<width$}", y + bounds.1.y + 1, width = width)?;
+                for _ in bounds.0.x..bounds.1.x + 1 {
+                    if y == 0 {
+                        write!(f, ".")?;
+                    } else {
+                        write!(f, "#")?;
+                    }
+                }
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn parse(input: &str) -> Result<Cave, AocError> {
+    Cave::from(input)
+}
+
+pub fn part1(cave: &Cave) -> usize {
+    cave.clone().fill_with_sand()
+}
+
+pub fn part2(cave: &Cave) -> usize {
+    let mut cave = cave.clone();
+    cave.add_floor();
+    cave.fill_with_sand()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn make_structure() {
+        let s = Structure::from("498,4 -> 498,6 -> 496,6").unwrap();
+        assert_eq!(s.bounds(), (Point::new(496, 4), Point::new(498, 6)));
+        let p1 = Point::new(498, 4);
+        let p2 = Point::new(498, 6);
+        let p3 = Point::new(496, 6);
+        assert_eq!(s.lines[0].start, p1);
+        assert_eq!(s.lines[0].end, p2);
+        assert_eq!(s.lines[1].start, p2);
+        assert_eq!(s.lines[1].end, p3);
+    }
+
+    #[test]
+    fn structure_occupied() {
+        let s = Structure::from("498,4 -> 498,6 -> 496,6").unwrap();
+        let in_points = [
+            Point::new(498, 5),
+            Point::new(498, 4),
+            Point::new(498, 6),
+            Point::new(497, 6),
+        ];
+        let out_points = [
+            Point::new(498, 3),
+            Point::new(498, 7),
+            Point::new(495, 6),
+            Point::new(499, 4),
+            Point::new(499, 3),
+            Point::new(493, 9),
+        ];
+
+        for p in in_points {
+            assert!(s.occupied(&p));
+        }
+        for p in out_points {
+            assert!(!s.occupied(&p), "{:?}", p);
+        }
+    }
+
+    #[test]
+    fn cave_in_bounds() {
+        let s = Cave::from("498,4 -> 498,6 -> 496,6\r\n503,4 -> 502,4 -> 502,9 -> 494,9").unwrap();
+        assert!(s.in_bounds(&Point::new(500, 0)));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let input = "498,4 -> 498,6\n503,4 -> 502\n";
+        let e = Cave::from(input).err().unwrap();
+        assert!(matches!(e, AocError::Parse { line: 2, .. }), "{e}");
+
+        assert!(Structure::from("498,4").is_err());
+        assert!(Structure::from("498,4 -> 500,6").is_err());
+        assert!(Cave::from("").is_err());
+    }
+}
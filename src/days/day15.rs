@@ -0,0 +1,264 @@
+use crate::{
+    error::AocError,
+    geometry::{Line, Point},
+};
+use regex::Regex;
+
+// Row checked in part 1 and the search area for part 2 on the real input
+pub const ROW: i32 = 2000000;
+pub const SEARCH_MAX: i32 = 4000000;
+
+fn try_combine_lines(l1: &Option<Line<i32>>, l2: &Option<Line<i32>>) -> Option<Line<i32>> {
+    if l1.is_none() || l2.is_none() {
+        return None;
+    }
+
+    let l1 = l1.unwrap();
+    let l2 = l2.unwrap();
+
+    // As long as any part overlaps, they can be combined as [min x]..[max x]
+    // This can easily be tested as the length must be <= the total length of the two
+    let max_len = l1.length_components().x + l2.length_components().x + 1;
+
+    let new_line = Line::new(
+        Point::new(l1.start.x.min(l2.start.x), l1.start.y.min(l2.start.y)),
+        Point::new(l1.end.x.max(l2.end.x), l1.end.y.max(l2.end.y)),
+    );
+
+    if new_line.length_components().x <= max_len {
+        Some(new_line)
+    } else {
+        None
+    }
+}
+
+fn combine_lines(lines: Vec<Line<i32>>) -> Vec<Line<i32>> {
+    let mut lines = lines
+        .iter()
+        .map(|x| Some(*x))
+        .collect::<Vec<Option<Line<i32>>>>();
+
+    let mut none_found = 0;
+    while none_found != 2 {
+        none_found += 1;
+        for i in 0..lines.len() {
+            for j in 0..lines.len() {
+                if i == j {
+                    continue;
+                }
+                if let Some(x) = try_combine_lines(&lines[i], &lines[j]) {
+                    lines[i] = Some(x);
+                    lines[j].take();
+                    none_found = 0;
+                }
+            }
+        }
+        lines.retain(|x| x.is_some());
+    }
+
+    lines.iter().filter_map(|x| *x).collect()
+}
+
+fn locations_with_no_beacon(lines: &[Line<i32>], beacons: &[Point<i32>], row: i32) -> i32 {
+    let mut beacons = beacons.to_vec();
+    beacons.sort_by_key(|a| a.x);
+    beacons.dedup_by(|a, b| a.x == b.x && a.y == b.y);
+    let beacon_count = beacons.iter().filter(|p| p.y == row).count() as i32;
+
+    let mut sum = 0;
+    for line in lines.iter() {
+        sum += line.length_components().x + 1;
+    }
+    sum - beacon_count
+}
+
+// Find the first x in [0, max] not covered by any of the combined lines
+fn beacon_location(lines: &[Line<i32>], max: i32) -> Option<i32> {
+    let mut lines = lines
+        .iter()
+        .filter(|l| l.end.x >= 0 && l.start.x <= max)
+        .collect::<Vec<&Line<i32>>>();
+    lines.sort_by_key(|l| l.start.x);
+
+    let mut x = 0;
+    for line in lines {
+        if line.start.x > x {
+            return Some(x);
+        }
+        x = x.max(line.end.x + 1);
+    }
+
+    if x <= max {
+        Some(x)
+    } else {
+        None
+    }
+}
+
+fn manhattan_circle_slice(center: Point<i32>, radius: i32, y: i32) -> Option<Line<i32>> {
+    if y < center.y {
+        if center.y - radius > y {
+            return None;
+        }
+
+        let width = (y - (center.y - radius)) * 2 + 1;
+
+        Some(Line::new(
+            Point::new(center.x - (width / 2), y),
+            Point::new(center.x + (width / 2), y),
+        ))
+    } else if y > center.y {
+        if center.y + radius < y {
+            return None;
+        }
+
+        let width = ((center.y + radius) - y) * 2 + 1;
+
+        Some(Line::new(
+            Point::new(center.x - (width / 2), y),
+            Point::new(center.x + (width / 2), y),
+        ))
+    } else {
+        Some(Line::new(
+            Point::new(center.x - radius, y),
+            Point::new(center.x + radius, y),
+        ))
+    }
+}
+
+// Sensor location and distance to its closest beacon
+type Sensor = (Point<i32>, i32);
+
+pub struct Sensors {
+    points: Vec<Sensor>,
+    beacons: Vec<Point<i32>>,
+}
+
+pub fn parse(input: &str) -> Result<Sensors, AocError> {
+    let mut beacons = Vec::new();
+    let mut points = Vec::new();
+
+    // Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+    let re = Regex::new(r"(x|y)=(-?\d+)").unwrap();
+    for line in input.lines().filter(|l| !l.trim().is_empty()) {
+        let coords = re
+            .captures_iter(line)
+            .map(|c| c[2].parse::<i32>().ok())
+            .collect::<Option<Vec<i32>>>();
+        let Some([x1, y1, x2, y2]) = coords.and_then(|c| <[i32; 4]>::try_from(c).ok()) else {
+            return Err(AocError::parse(
+                input,
+                line,
+                "Expected a sensor and a beacon",
+            ));
+        };
+        let p1 = Point::new(x1, y1);
+        let p2 = Point::new(x2, y2);
+
+        let dist = p1.manhattan_distance(&p2);
+        points.push((p1, dist));
+        beacons.push(p2);
+    }
+
+    Ok(Sensors { points, beacons })
+}
+
+fn covered_lines(points: &[Sensor], row: i32) -> Vec<Line<i32>> {
+    let mut lines = Vec::new();
+    for (p, dist) in points.iter() {
+        if let Some(x) = manhattan_circle_slice(*p, *dist, row) {
+            lines.push(x);
+        }
+    }
+
+    combine_lines(lines)
+}
+
+pub fn part1(sensors: &Sensors, row: i32) -> i32 {
+    let lines = covered_lines(&sensors.points, row);
+
+    locations_with_no_beacon(&lines, &sensors.beacons, row)
+}
+
+pub fn part2(sensors: &Sensors, max: i32) -> Result<u64, AocError> {
+    for y in 0..max + 1 {
+        let lines = covered_lines(&sensors.points, y);
+        if let Some(x) = beacon_location(&lines, max) {
+            return Ok((x as u64) * 4000000 + (y as u64));
+        }
+    }
+
+    Err(AocError::NoSolution(format!(
+        "Every position up to {max} is covered"
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rows() {
+        let center = Point::new(8, 7);
+        let dist = 9;
+        let out_above2 = manhattan_circle_slice(center, dist, -30);
+        let out_above = manhattan_circle_slice(center, dist, -3);
+        let above = manhattan_circle_slice(center, dist, 4);
+        let same = manhattan_circle_slice(center, dist, 7);
+        let below = manhattan_circle_slice(center, dist, 15);
+        let out_below = manhattan_circle_slice(center, dist, 17);
+        let out_below2 = manhattan_circle_slice(center, dist, 30);
+
+        assert!(out_above2.is_none());
+        assert!(out_above.is_none());
+        assert!(out_below.is_none());
+        assert!(out_below2.is_none());
+
+        assert_eq!(
+            above.unwrap(),
+            Line::new(Point::new(2, 4), Point::new(14, 4))
+        );
+
+        assert_eq!(
+            same.unwrap(),
+            Line::new(Point::new(-1, 7), Point::new(17, 7))
+        );
+
+        assert_eq!(
+            below.unwrap(),
+            Line::new(Point::new(7, 15), Point::new(9, 15))
+        );
+    }
+
+    fn horizontal_line<const N: i32>(x1: i32, x2: i32) -> Line<i32> {
+        Line::new(Point::new(x1, N), Point::new(x2, N))
+    }
+
+    #[test]
+    fn test_overlap() {
+        // Lines: [2, 5], [8, 11], [24, 27], [3, 18], [21, 23], [2, 5]
+        // Beacons: 10, 21
+        // 0123456789012345678901234567890
+        // ..####..####..####......####...
+        // ...################..###.......
+        // ..####..#..........#...........
+        // =.##################.#######...
+        // 25 - 2 = 23
+        let lines = vec![
+            horizontal_line::<10>(2, 2),
+            horizontal_line::<10>(2, 5),
+            horizontal_line::<10>(8, 11),
+            horizontal_line::<10>(24, 27),
+            horizontal_line::<10>(3, 18),
+            horizontal_line::<10>(21, 23),
+            horizontal_line::<10>(2, 5),
+            horizontal_line::<10>(8, 8),
+            horizontal_line::<10>(19, 19),
+        ];
+
+        let beacons = vec![Point::new(10, 10), Point::new(21, 10)];
+        let lines = combine_lines(lines);
+
+        assert_eq!(23, locations_with_no_beacon(&lines, &beacons, 10));
+    }
+}
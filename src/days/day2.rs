@@ -0,0 +1,239 @@
+use crate::error::AocError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+    Lizard,
+    Spock,
+}
+
+impl Shape {
+    pub fn score(self) -> i32 {
+        self as i32 + 1
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub fn score(self) -> i32 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+// The second column of the strategy guide. Part 1 reads it as our shape,
+// part 2 as the outcome we need.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Response {
+    X,
+    Y,
+    Z,
+}
+
+impl Response {
+    pub fn shape(self) -> Shape {
+        match self {
+            Response::X => Shape::Rock,
+            Response::Y => Shape::Paper,
+            Response::Z => Shape::Scissors,
+        }
+    }
+
+    pub fn outcome(self) -> Outcome {
+        match self {
+            Response::X => Outcome::Lose,
+            Response::Y => Outcome::Draw,
+            Response::Z => Outcome::Win,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub theirs: Shape,
+    pub response: Response,
+}
+
+// Which shapes are in play and which beats which
+pub trait Rules {
+    fn shapes(&self) -> &[Shape];
+
+    fn beats(&self, a: Shape, b: Shape) -> bool;
+
+    fn play(&self, ours: Shape, theirs: Shape) -> Outcome {
+        if self.beats(ours, theirs) {
+            Outcome::Win
+        } else if self.beats(theirs, ours) {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    // The first shape in play that gives the outcome
+    fn shape_for(&self, theirs: Shape, outcome: Outcome) -> Option<Shape> {
+        self.shapes()
+            .iter()
+            .copied()
+            .find(|s| self.play(*s, theirs) == outcome)
+    }
+
+    fn score(&self, ours: Shape, theirs: Shape) -> i32 {
+        ours.score() + self.play(ours, theirs).score()
+    }
+}
+
+pub struct Classic;
+
+impl Rules for Classic {
+    fn shapes(&self) -> &[Shape] {
+        &[Shape::Rock, Shape::Paper, Shape::Scissors]
+    }
+
+    fn beats(&self, a: Shape, b: Shape) -> bool {
+        matches!(
+            (a, b),
+            (Shape::Rock, Shape::Scissors)
+                | (Shape::Paper, Shape::Rock)
+                | (Shape::Scissors, Shape::Paper)
+        )
+    }
+}
+
+// Rock-paper-scissors-lizard-Spock
+pub struct Extended;
+
+impl Rules for Extended {
+    fn shapes(&self) -> &[Shape] {
+        &[
+            Shape::Rock,
+            Shape::Paper,
+            Shape::Scissors,
+            Shape::Lizard,
+            Shape::Spock,
+        ]
+    }
+
+    fn beats(&self, a: Shape, b: Shape) -> bool {
+        use Shape::*;
+        matches!(
+            (a, b),
+            (Scissors, Paper)
+                | (Paper, Rock)
+                | (Rock, Lizard)
+                | (Lizard, Spock)
+                | (Spock, Scissors)
+                | (Scissors, Lizard)
+                | (Lizard, Paper)
+                | (Paper, Spock)
+                | (Spock, Rock)
+                | (Rock, Scissors)
+        )
+    }
+}
+
+// A Y
+fn parse_round(line: &str) -> Option<Round> {
+    let [theirs, b' ', response] = line.trim().as_bytes() else {
+        return None;
+    };
+
+    let theirs = match theirs {
+        b'A' => Shape::Rock,
+        b'B' => Shape::Paper,
+        b'C' => Shape::Scissors,
+        _ => return None,
+    };
+    let response = match response {
+        b'X' => Response::X,
+        b'Y' => Response::Y,
+        b'Z' => Response::Z,
+        _ => return None,
+    };
+
+    Some(Round { theirs, response })
+}
+
+pub fn parse(input: &str) -> Result<Vec<Round>, AocError> {
+    input
+        .lines()
+        .map(|l| {
+            parse_round(l).ok_or_else(|| AocError::parse(input, l, "Expected a round like \"A Y\""))
+        })
+        .collect()
+}
+
+// The second column is the shape to play
+pub fn play_shapes<R: Rules>(rules: &R, rounds: &[Round]) -> i32 {
+    rounds
+        .iter()
+        .map(|r| rules.score(r.response.shape(), r.theirs))
+        .sum()
+}
+
+// The second column is how the round needs to end
+pub fn play_outcomes<R: Rules>(rules: &R, rounds: &[Round]) -> Result<i32, AocError> {
+    rounds.iter().try_fold(0, |sum, r| {
+        let outcome = r.response.outcome();
+        let ours = rules.shape_for(r.theirs, outcome).ok_or_else(|| {
+            AocError::NoSolution(format!(
+                "No shape gives a {outcome:?} against {:?}",
+                r.theirs
+            ))
+        })?;
+
+        Ok(sum + rules.score(ours, r.theirs))
+    })
+}
+
+pub fn part1(rounds: &[Round]) -> i32 {
+    play_shapes(&Classic, rounds)
+}
+
+pub fn part2(rounds: &[Round]) -> Result<i32, AocError> {
+    play_outcomes(&Classic, rounds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules() {
+        for rules in [&Classic as &dyn Rules, &Extended] {
+            for a in rules.shapes() {
+                assert_eq!(rules.play(*a, *a), Outcome::Draw);
+                // Every shape beats exactly half of the others
+                let wins = rules.shapes().iter().filter(|b| rules.beats(*a, **b));
+                assert_eq!(wins.count(), rules.shapes().len() / 2);
+            }
+        }
+
+        assert_eq!(Classic.play(Shape::Paper, Shape::Rock), Outcome::Win);
+        assert_eq!(Extended.play(Shape::Spock, Shape::Lizard), Outcome::Lose);
+        assert_eq!(
+            Extended.shape_for(Shape::Rock, Outcome::Win),
+            Some(Shape::Paper)
+        );
+    }
+
+    #[test]
+    fn guide_under_other_rules() {
+        let rounds = parse("A Y\nB X\nC Z\n").unwrap();
+        assert_eq!(play_shapes(&Extended, &rounds), 15);
+        // Spock also loses to paper, but rock comes first
+        assert_eq!(play_outcomes(&Extended, &rounds).unwrap(), 12);
+        assert!(parse("A W").is_err());
+    }
+}
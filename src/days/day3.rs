@@ -0,0 +1,81 @@
+use crate::{charset::CharSet, error::AocError, iter::IterExt};
+
+#[derive(Debug)]
+pub struct Rucksack {
+    left: CharSet,
+    right: CharSet,
+}
+
+impl Rucksack {
+    pub fn items(&self) -> CharSet {
+        self.left | self.right
+    }
+}
+
+fn score(common: CharSet) -> i32 {
+    common.priority_sum() as i32
+}
+
+// Elves are in groups of three, so a group that is cut short is an error
+pub fn parse(input: &str) -> Result<Vec<Rucksack>, AocError> {
+    let rucksacks = input
+        .lines()
+        .map(|l| {
+            if !l.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(AocError::parse(input, l, "Items must be letters"));
+            } else if !l.len().is_multiple_of(2) {
+                return Err(AocError::parse(
+                    input,
+                    l,
+                    "Compartments must be the same size",
+                ));
+            }
+
+            let (left, right) = l.split_at(l.len() / 2);
+            Ok(Rucksack {
+                left: CharSet::try_from(left)?,
+                right: CharSet::try_from(right)?,
+            })
+        })
+        .collect::<Result<Vec<Rucksack>, AocError>>()?;
+
+    if !rucksacks.len().is_multiple_of(3) {
+        let last = input.lines().last().unwrap_or(input);
+        return Err(AocError::parse(
+            input,
+            last,
+            "Expected rucksacks in groups of three",
+        ));
+    }
+    Ok(rucksacks)
+}
+
+pub fn part1(rucksacks: &[Rucksack]) -> i32 {
+    rucksacks
+        .iter()
+        .fold(0, |sum, r| sum + score(r.left & r.right))
+}
+
+pub fn part2(rucksacks: &[Rucksack]) -> i32 {
+    rucksacks
+        .iter()
+        .chunks_exact_array::<3>()
+        .fold(0, |sum, group| {
+            let [a, b, c] = group.map(|r| r.items());
+            sum + score(a & b & c)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups() {
+        let input = include_str!("../../fixtures/day3.txt");
+        let two = input.lines().take(2).collect::<Vec<&str>>().join("\n");
+        let e = parse(&two).unwrap_err();
+        assert!(matches!(e, AocError::Parse { line: 2, .. }), "{e}");
+        assert!(parse("").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,52 @@
+use crate::error::AocError;
+
+#[derive(Debug)]
+pub struct Section {
+    lower: i32,
+    upper: i32,
+}
+
+impl Section {
+    pub fn overlap_all(&self, other: &Section) -> bool {
+        (self.lower >= other.lower && self.upper <= other.upper)
+            || (other.lower >= self.lower && other.upper <= self.upper)
+    }
+    pub fn overlap_some(&self, other: &Section) -> bool {
+        (self.lower >= other.lower && self.lower <= other.upper)
+            || (self.upper <= other.upper && self.upper >= other.lower)
+            || (other.lower >= self.lower && other.lower <= self.upper)
+            || (other.upper <= self.upper && other.upper >= self.lower)
+    }
+}
+
+// ##-##
+fn parse_section(s: &str) -> Option<Section> {
+    let (lower, upper) = s.split_once('-')?;
+    Some(Section {
+        lower: lower.trim().parse().ok()?,
+        upper: upper.trim().parse().ok()?,
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<Section>>, AocError> {
+    input
+        .lines()
+        .map(|l| {
+            l.split_once(',')
+                .and_then(|(a, b)| Some(vec![parse_section(a)?, parse_section(b)?]))
+                .ok_or_else(|| AocError::parse(input, l, "Expected a pair like 2-4,6-8"))
+        })
+        .collect()
+}
+
+pub fn part1(pairs: &[Vec<Section>]) -> i32 {
+    pairs
+        .iter()
+        .fold(0, |sum, l| sum + (l[0].overlap_all(&l[1]) as i32))
+}
+
+pub fn part2(pairs: &[Vec<Section>]) -> i32 {
+    pairs
+        .iter()
+        .fold(0, |sum, l| sum + (l[0].overlap_some(&l[1]) as i32))
+}
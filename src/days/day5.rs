@@ -0,0 +1,310 @@
+use crate::error::AocError;
+use std::fmt;
+
+// How a crane moves several crates from one stack to another
+pub trait Crane {
+    // Takes count crates off the top of the stack, returned in the order they
+    // are put down on the destination. None if the stack is too small.
+    fn lift(&self, stack: &mut Vec<String>, count: usize) -> Option<Vec<String>>;
+}
+
+// Moves one crate at a time, so the moved crates end up reversed
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn lift(&self, stack: &mut Vec<String>, count: usize) -> Option<Vec<String>> {
+        let range = stack.len().checked_sub(count)?..;
+        Some(stack.drain(range).rev().collect())
+    }
+}
+
+// Moves all the crates at once, keeping their order
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn lift(&self, stack: &mut Vec<String>, count: usize) -> Option<Vec<String>> {
+        let range = stack.len().checked_sub(count)?..;
+        Some(stack.drain(range).collect())
+    }
+}
+
+// Stacks are numbered from 1, as in the puzzle. line is where the move is in
+// the input, 0 if it was not read from one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+    pub line: usize,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.count, self.from, self.to)
+    }
+}
+
+// Bottom crate first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stacks {
+    stacks: Vec<Vec<String>>,
+}
+
+impl Stacks {
+    pub fn len(&self) -> usize {
+        self.stacks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stacks.is_empty()
+    }
+
+    pub fn stack(&self, number: usize) -> Option<&[String]> {
+        number
+            .checked_sub(1)
+            .and_then(|i| self.stacks.get(i))
+            .map(|s| s.as_slice())
+    }
+
+    // Errors point at the line of the move
+    pub fn apply<C: Crane>(&mut self, crane: &C, m: &Move) -> Result<(), AocError> {
+        let error = |reason: &str| AocError::at_line(m.line, &m.to_string(), reason);
+        if self.stack(m.to).is_none() {
+            return Err(error("Move to a missing stack"));
+        }
+
+        let from = m.from.checked_sub(1).and_then(|i| self.stacks.get_mut(i));
+        let from = from.ok_or_else(|| error("Move from a missing stack"))?;
+        let available = from.len();
+        let mut lifted = crane.lift(from, m.count).ok_or_else(|| {
+            error(&format!(
+                "Stack {} has {available} crates, cannot move {}",
+                m.from, m.count
+            ))
+        })?;
+
+        self.stacks[m.to - 1].append(&mut lifted);
+        Ok(())
+    }
+
+    // The top crate of each non-empty stack
+    pub fn tops(&self) -> String {
+        self.stacks
+            .iter()
+            .filter_map(|s| s.last())
+            .map(|c| c.as_str())
+            .collect()
+    }
+}
+
+// Draws the stacks the way the puzzle does, every column as wide as the
+// longest label
+impl fmt::Display for Stacks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .stacks
+            .iter()
+            .flatten()
+            .map(|c| c.len() + 2)
+            .max()
+            .unwrap_or(3);
+        let height = self.stacks.iter().map(|s| s.len()).max().unwrap_or(0);
+
+        for row in (0..height).rev() {
+            let line = self
+                .stacks
+                .iter()
+                .map(|s| match s.get(row) {
+                    Some(c) => format!("{:<width$}", format!("[{c}]")),
+                    None => " ".repeat(width),
+                })
+                .collect::<Vec<String>>()
+                .join(" ");
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        let numbers = (1..=self.stacks.len())
+            .map(|n| format!("{n:^width$}"))
+            .collect::<Vec<String>>()
+            .join(" ");
+        write!(f, "{}", numbers.trim_end())
+    }
+}
+
+pub struct Cargo {
+    pub stacks: Stacks,
+    pub moves: Vec<Move>,
+}
+
+impl Cargo {
+    // Calls after_move with the stacks as they are after each move
+    pub fn operate<C, F>(&self, crane: &C, mut after_move: F) -> Result<Stacks, AocError>
+    where
+        C: Crane,
+        F: FnMut(&Move, &Stacks),
+    {
+        let mut stacks = self.stacks.clone();
+        for m in self.moves.iter() {
+            stacks.apply(crane, m)?;
+            after_move(m, &stacks);
+        }
+        Ok(stacks)
+    }
+}
+
+// Items of a line with the columns they span, e.g. stack numbers or [crates]
+fn tokens(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split(' ')
+        .scan(0, |start, token| {
+            let at = *start;
+            *start += token.len() + 1;
+            Some((at, token))
+        })
+        .filter(|(_, token)| !token.is_empty())
+}
+
+fn parse_drawing(input: &str, drawing: &str) -> Result<Stacks, AocError> {
+    let mut rows = drawing.lines().rev();
+    let numbers = rows.next().unwrap_or(drawing);
+
+    // The columns each stack number spans
+    let mut columns = Vec::new();
+    for (at, token) in tokens(numbers) {
+        if token.parse::<usize>() != Ok(columns.len() + 1) {
+            return Err(AocError::parse(
+                input,
+                numbers,
+                "Expected stacks numbered 1, 2, 3...",
+            ));
+        }
+        columns.push(at..at + token.len());
+    }
+    if columns.is_empty() {
+        return Err(AocError::parse(input, numbers, "Expected stack numbers"));
+    }
+
+    let mut stacks = vec![Vec::new(); columns.len()];
+    // Bottom row first, so a crate in row h sits on a stack h crates tall
+    for (height, row) in rows.enumerate() {
+        for (at, token) in tokens(row) {
+            let label = token
+                .strip_prefix('[')
+                .and_then(|t| t.strip_suffix(']'))
+                .filter(|l| !l.is_empty())
+                .ok_or_else(|| AocError::parse(input, row, "Expected crates like [A]"))?;
+
+            // The crate belongs to the stack whose number sits under it
+            let span = at..at + token.len();
+            let stack = columns
+                .iter()
+                .position(|c| c.start < span.end && span.start < c.end)
+                .ok_or_else(|| {
+                    AocError::parse(input, row, "Crate is not above a numbered stack")
+                })?;
+
+            if stacks[stack].len() != height {
+                return Err(AocError::parse(input, row, "Crate is floating in the air"));
+            }
+            stacks[stack].push(String::from(label));
+        }
+    }
+
+    Ok(Stacks { stacks })
+}
+
+// move 1 from 2 to 1, number is the line number
+fn parse_move(line: &str, number: usize) -> Option<Move> {
+    let ["move", count, "from", from, "to", to] =
+        line.split_whitespace().collect::<Vec<&str>>()[..]
+    else {
+        return None;
+    };
+
+    Some(Move {
+        count: count.parse().ok()?,
+        from: from.parse().ok()?,
+        to: to.parse().ok()?,
+        line: number,
+    })
+}
+
+pub fn parse(input: &str) -> Result<Cargo, AocError> {
+    // Split out stacks from operations
+    let (drawing, moves) = input.split_once("\n\n").ok_or_else(|| {
+        AocError::parse(
+            input,
+            input,
+            "Expected the stacks, a blank line, then moves",
+        )
+    })?;
+
+    let stacks = parse_drawing(input, drawing)?;
+
+    // Moves start after the drawing and the blank line
+    let first = drawing.lines().count() + 2;
+    let moves = moves
+        .lines()
+        .zip(first..)
+        .map(|(l, number)| {
+            parse_move(l, number)
+                .filter(|m| stacks.stack(m.from).is_some() && stacks.stack(m.to).is_some())
+                .ok_or_else(|| AocError::parse(input, l, "Expected a move between numbered stacks"))
+        })
+        .collect::<Result<Vec<Move>, AocError>>()?;
+
+    Ok(Cargo { stacks, moves })
+}
+
+pub fn part1(cargo: &Cargo) -> Result<String, AocError> {
+    Ok(cargo.operate(&CrateMover9000, |_, _| {})?.tops())
+}
+
+pub fn part2(cargo: &Cargo) -> Result<String, AocError> {
+    Ok(cargo.operate(&CrateMover9001, |_, _| {})?.tops())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/day5.txt");
+
+    #[test]
+    fn cranes() {
+        let cargo = parse(EXAMPLE).unwrap();
+        let mut frames = Vec::new();
+        cargo
+            .operate(&CrateMover9000, |_, s| frames.push(s.to_string()))
+            .unwrap();
+        assert_eq!(frames[0], "[D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3");
+        assert_eq!(frames.len(), 4);
+    }
+
+    #[test]
+    fn long_labels() {
+        let input = "[AB]\n[CD]      [E]\n 1    2    3\n\nmove 2 from 1 to 2\n";
+        let cargo = parse(input).unwrap();
+        assert_eq!(cargo.stacks.stack(1).unwrap(), ["CD", "AB"]);
+        assert_eq!(cargo.stacks.stack(3).unwrap(), ["E"]);
+
+        // Rendering and parsing again gives the same stacks
+        let drawing = format!("{}\n\nmove 1 from 1 to 2\n", cargo.stacks);
+        assert_eq!(parse(&drawing).unwrap().stacks, cargo.stacks);
+        assert_eq!(part2(&cargo).unwrap(), "ABE");
+    }
+
+    #[test]
+    fn errors() {
+        let input = "[A]\n 1   2\n\nmove 2 from 1 to 2\n";
+        let e = part1(&parse(input).unwrap()).unwrap_err();
+        assert!(matches!(e, AocError::Parse { line: 4, .. }), "{e}");
+        assert!(e
+            .to_string()
+            .contains("Stack 1 has 1 crates, cannot move 2"));
+        assert_eq!(CrateMover9001.lift(&mut vec![String::from("A")], 2), None);
+
+        assert!(parse("[A]\n 1\n\nmove 1 from 1 to 2\n").is_err());
+        assert!(parse("    [A]\n[B]\n 1   2\n\nmove 1 from 2 to 1\n").is_err());
+        assert!(parse("        [A]\n 1   2\n\nmove 1 from 2 to 1\n").is_err());
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{error::AocError, marker::MarkerScanner};
+
+pub fn parse(input: &str) -> Result<Vec<u8>, AocError> {
+    let signal = input.trim_end();
+    if let Some(line) = signal.lines().nth(1) {
+        return Err(AocError::parse(input, line, "Expected a single line"));
+    }
+    if !signal.is_ascii() {
+        return Err(AocError::parse(input, signal, "Expected ASCII characters"));
+    }
+
+    Ok(signal.as_bytes().to_vec())
+}
+
+fn solve(input: &[u8], window_size: usize) -> Result<usize, AocError> {
+    let mut scanner = MarkerScanner::new(&[window_size]);
+    scanner.feed(input);
+
+    scanner.markers()[0]
+        .map(|p| p as usize)
+        .ok_or(AocError::NoSolution(format!(
+            "No {window_size} distinct characters in a row"
+        )))
+}
+
+pub fn part1(input: &[u8]) -> Result<usize, AocError> {
+    solve(input, 4)
+}
+
+pub fn part2(input: &[u8]) -> Result<usize, AocError> {
+    solve(input, 14)
+}
@@ -0,0 +1,283 @@
+use crate::error::AocError;
+use std::fmt;
+
+const DISK_SIZE: u64 = 70000000;
+const UPDATE_SIZE: u64 = 30000000;
+
+// Index into FileSystem::nodes, the root is 0
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Kind {
+    Dir(Vec<NodeId>),
+    File(u64),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub name: String,
+    pub parent: Option<NodeId>,
+    pub kind: Kind,
+}
+
+impl Node {
+    pub fn is_dir(&self) -> bool {
+        matches!(self.kind, Kind::Dir(_))
+    }
+}
+
+// Nodes live in one Vec and refer to each other by index. A child is always
+// added after its parent, so it has the higher id.
+#[derive(Debug, Clone)]
+pub struct FileSystem {
+    nodes: Vec<Node>,
+}
+
+impl FileSystem {
+    pub const ROOT: NodeId = 0;
+
+    pub fn new() -> FileSystem {
+        FileSystem {
+            nodes: vec![Node {
+                name: String::from("/"),
+                parent: None,
+                kind: Kind::Dir(Vec::new()),
+            }],
+        }
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id]
+    }
+
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        match &self.nodes[id].kind {
+            Kind::Dir(children) => children,
+            Kind::File(_) => &[],
+        }
+    }
+
+    pub fn child(&self, dir: NodeId, name: &str) -> Option<NodeId> {
+        self.children(dir)
+            .iter()
+            .copied()
+            .find(|c| self.nodes[*c].name == name)
+    }
+
+    // Adding a name that is already there returns the existing node, so
+    // listing a directory twice changes nothing
+    pub fn add(&mut self, dir: NodeId, name: &str, kind: Kind) -> Result<NodeId, String> {
+        if !self.nodes[dir].is_dir() {
+            return Err(format!("{} is not a directory", self.path(dir)));
+        }
+        if let Some(existing) = self.child(dir, name) {
+            return match (&self.nodes[existing].kind, &kind) {
+                (Kind::Dir(_), Kind::Dir(_)) => Ok(existing),
+                (Kind::File(a), Kind::File(b)) if a == b => Ok(existing),
+                _ => Err(format!(
+                    "{} was listed differently before",
+                    self.path(existing)
+                )),
+            };
+        }
+
+        let id = self.nodes.len();
+        self.nodes.push(Node {
+            name: String::from(name),
+            parent: Some(dir),
+            kind,
+        });
+        if let Kind::Dir(children) = &mut self.nodes[dir].kind {
+            children.push(id);
+        }
+        Ok(id)
+    }
+
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = Vec::new();
+        let mut node = Some(id);
+        while let Some(n) = node.filter(|n| *n != FileSystem::ROOT) {
+            names.push(self.nodes[n].name.as_str());
+            node = self.nodes[n].parent;
+        }
+
+        if names.is_empty() {
+            return String::from("/");
+        }
+        names.iter().rev().map(|n| format!("/{n}")).collect()
+    }
+
+    // Total size of every node, indexed by NodeId
+    pub fn du(&self) -> Vec<u64> {
+        let mut totals = self
+            .nodes
+            .iter()
+            .map(|n| match n.kind {
+                Kind::File(size) => size,
+                Kind::Dir(_) => 0,
+            })
+            .collect::<Vec<u64>>();
+
+        // Children come after their parents, so walking backwards finishes
+        // each node before it is added to its parent
+        for id in (1..self.nodes.len()).rev() {
+            if let Some(parent) = self.nodes[id].parent {
+                totals[parent] += totals[id];
+            }
+        }
+        totals
+    }
+
+    // Directories and their total sizes that match the predicate
+    pub fn find_dirs<F>(&self, mut predicate: F) -> Vec<(NodeId, u64)>
+    where
+        F: FnMut(&Node, u64) -> bool,
+    {
+        self.du()
+            .into_iter()
+            .enumerate()
+            .filter(|(id, size)| {
+                let node = &self.nodes[*id];
+                node.is_dir() && predicate(node, *size)
+            })
+            .collect()
+    }
+
+    fn write_node(&self, f: &mut fmt::Formatter<'_>, id: NodeId, depth: usize) -> fmt::Result {
+        let node = &self.nodes[id];
+        let indent = "  ".repeat(depth);
+        match node.kind {
+            Kind::Dir(_) => writeln!(f, "{indent}- {} (dir)", node.name)?,
+            Kind::File(size) => writeln!(f, "{indent}- {} (file, size={size})", node.name)?,
+        }
+
+        let mut children = self.children(id).to_vec();
+        children.sort_by(|a, b| self.nodes[*a].name.cmp(&self.nodes[*b].name));
+        for c in children {
+            self.write_node(f, c, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for FileSystem {
+    fn default() -> FileSystem {
+        FileSystem::new()
+    }
+}
+
+// The puzzle's tree, children sorted by name
+impl fmt::Display for FileSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_node(f, FileSystem::ROOT, 0)
+    }
+}
+
+// Replays the commands and their output to rebuild the tree
+pub fn parse(input: &str) -> Result<FileSystem, AocError> {
+    let mut fs = FileSystem::new();
+    let mut cwd = FileSystem::ROOT;
+    // Output lines are only valid right after an ls
+    let mut listing = false;
+
+    for l in input.lines() {
+        let error = |reason: &str| AocError::parse(input, l, reason);
+        let words = l.split_whitespace().collect::<Vec<&str>>();
+
+        match words[..] {
+            ["$", "ls"] => listing = true,
+            ["$", "cd", dir] => {
+                listing = false;
+                cwd = match dir {
+                    "/" => FileSystem::ROOT,
+                    // cd .. in the root stays there, like a shell
+                    ".." => fs.node(cwd).parent.unwrap_or(FileSystem::ROOT),
+                    _ => fs
+                        .add(cwd, dir, Kind::Dir(Vec::new()))
+                        .map_err(|e| error(&e))?,
+                };
+            }
+            ["$", ..] => return Err(error("Expected cd or ls")),
+            _ if !listing => return Err(error("Expected a command")),
+            ["dir", name] => {
+                fs.add(cwd, name, Kind::Dir(Vec::new()))
+                    .map_err(|e| error(&e))?;
+            }
+            [size, name] => {
+                let size = size
+                    .parse::<u64>()
+                    .map_err(|_| error("Expected a file size"))?;
+                fs.add(cwd, name, Kind::File(size)).map_err(|e| error(&e))?;
+            }
+            _ => return Err(error("Expected a directory or a file")),
+        }
+    }
+
+    Ok(fs)
+}
+
+pub fn part1(fs: &FileSystem) -> u64 {
+    fs.find_dirs(|_, size| size <= 100000)
+        .iter()
+        .map(|(_, size)| size)
+        .sum()
+}
+
+pub fn part2(fs: &FileSystem) -> Result<u64, AocError> {
+    let used = fs.du()[FileSystem::ROOT];
+    let to_free = UPDATE_SIZE.saturating_sub(DISK_SIZE.saturating_sub(used));
+
+    fs.find_dirs(|_, size| size >= to_free)
+        .iter()
+        .map(|(_, size)| *size)
+        .min()
+        .ok_or(AocError::NoSolution(String::from(
+            "No directory frees enough space",
+        )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/day7.txt");
+
+    #[test]
+    fn tree() {
+        let fs = parse(EXAMPLE).unwrap();
+        let e = fs
+            .child(fs.child(FileSystem::ROOT, "a").unwrap(), "e")
+            .unwrap();
+        assert_eq!(fs.path(e), "/a/e");
+        assert_eq!(fs.du()[e], 584);
+
+        let tree = fs.to_string();
+        assert!(
+            tree.starts_with("- / (dir)\n  - a (dir)\n    - e (dir)\n      - i (file, size=584)\n")
+        );
+        assert_eq!(tree.lines().count(), 14);
+    }
+
+    #[test]
+    fn repeated_listings() {
+        // Listing again and jumping back to the root must not count twice
+        let input =
+            format!("{EXAMPLE}$ cd /\n$ ls\ndir a\n14848514 b.txt\n$ cd a\n$ ls\n29116 f\n");
+        let fs = parse(&input).unwrap();
+        assert_eq!(fs.du()[FileSystem::ROOT], 48381165);
+
+        let big = fs.find_dirs(|node, size| node.name != "/" && size > 1000000);
+        assert_eq!(
+            big.iter().map(|(id, _)| fs.path(*id)).collect::<Vec<_>>(),
+            ["/d"]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert!(parse("$ cd /\n123 a\n").is_err());
+        assert!(parse("$ ls\n1 a\n$ ls\n2 a\n").is_err());
+        assert!(parse("$ ls\n1 a\n$ cd a\n").is_err());
+        assert!(parse("$ rm -rf /\n").is_err());
+    }
+}
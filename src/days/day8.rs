@@ -0,0 +1,233 @@
+use crate::{
+    error::AocError,
+    geometry::{BoundingBox, Grid2d},
+};
+
+// From sparse to dense, used to draw the scenic heatmap
+const HEAT: &[char] = &[' ', '.', ':', '-', '=', '+', '*', '#', '%', '@'];
+
+// The way a tree is looking
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+}
+
+pub struct Forest {
+    heights: Grid2d<u8>,
+    width: i32,
+    height: i32,
+}
+
+impl Forest {
+    pub fn from(s: &str) -> Result<Forest, AocError> {
+        let rows = s
+            .lines()
+            .map(|l| {
+                l.chars()
+                    .map(|c| c.to_digit(10).map(|d| d as u8))
+                    .collect::<Option<Vec<u8>>>()
+                    .ok_or_else(|| AocError::parse(s, l, "Tree heights must be digits"))
+            })
+            .collect::<Result<Vec<Vec<u8>>, AocError>>()?;
+
+        if rows.is_empty() || rows[0].is_empty() {
+            return Err(AocError::parse(s, s, "No trees"));
+        }
+        if let Some(l) = s.lines().find(|l| l.len() != rows[0].len()) {
+            return Err(AocError::parse(s, l, "Rows must be the same length"));
+        }
+
+        let mut heights = Grid2d::with_size(rows[0].len(), rows.len(), 0);
+        for (y, row) in rows.iter().enumerate() {
+            for (x, h) in row.iter().enumerate() {
+                *heights.index_mut(x as i32, y as i32)? = *h;
+            }
+        }
+
+        let bounds = heights.bounds();
+        Ok(Forest {
+            heights,
+            width: bounds.width(),
+            height: bounds.height(),
+        })
+    }
+
+    // Cells in lines that start at the edge the trees look towards, e.g.
+    // rows from left to right when looking left
+    fn lines(&self, dir: Direction) -> Vec<Vec<(i32, i32)>> {
+        let (w, h) = (self.width, self.height);
+        match dir {
+            Direction::Left => (0..h).map(|y| (0..w).map(|x| (x, y)).collect()).collect(),
+            Direction::Right => (0..h)
+                .map(|y| (0..w).rev().map(|x| (x, y)).collect())
+                .collect(),
+            Direction::Up => (0..w).map(|x| (0..h).map(|y| (x, y)).collect()).collect(),
+            Direction::Down => (0..w)
+                .map(|x| (0..h).rev().map(|y| (x, y)).collect())
+                .collect(),
+        }
+    }
+
+    // Calls f with each tree, whether it can be seen from the edge in that
+    // direction and how many trees it can see that way. A stack holds the
+    // trees behind the current one that nothing has hidden yet, tallest at
+    // the bottom, so every tree is pushed and popped once per line.
+    fn sweep<F>(&self, dir: Direction, mut f: F)
+    where
+        F: FnMut(i32, i32, bool, u32),
+    {
+        for line in self.lines(dir) {
+            let mut stack: Vec<(u8, u32)> = Vec::new();
+            for (i, (x, y)) in line.into_iter().enumerate() {
+                let i = i as u32;
+                let h = *self.heights.index(x, y).unwrap_or(&0);
+
+                while stack.last().is_some_and(|(top, _)| *top < h) {
+                    stack.pop();
+                }
+                // The view stops at the first tree at least as tall, or the edge
+                let (visible, distance) = match stack.last() {
+                    Some((_, at)) => (false, i - at),
+                    None => (true, i),
+                };
+
+                f(x, y, visible, distance);
+                stack.push((h, i));
+            }
+        }
+    }
+
+    // Trees that can be seen from outside the forest
+    pub fn visibility(&self) -> Grid2d<bool> {
+        let mut visible = Grid2d::with_size(self.width as usize, self.height as usize, false);
+        for dir in Direction::ALL {
+            self.sweep(dir, |x, y, v, _| {
+                if let Ok(cell) = visible.index_mut(x, y) {
+                    *cell |= v;
+                }
+            });
+        }
+        visible
+    }
+
+    pub fn viewing_distances(&self, dir: Direction) -> Grid2d<u32> {
+        let mut distances = Grid2d::with_size(self.width as usize, self.height as usize, 0);
+        self.sweep(dir, |x, y, _, d| {
+            if let Ok(cell) = distances.index_mut(x, y) {
+                *cell = d;
+            }
+        });
+        distances
+    }
+
+    // Product of the viewing distances in all four directions
+    pub fn scenic_scores(&self) -> Grid2d<u64> {
+        let mut scores = Grid2d::with_size(self.width as usize, self.height as usize, 1);
+        for dir in Direction::ALL {
+            self.sweep(dir, |x, y, _, d| {
+                if let Ok(cell) = scores.index_mut(x, y) {
+                    *cell *= d as u64;
+                }
+            });
+        }
+        scores
+    }
+
+    pub fn visible_trees(&self) -> usize {
+        self.visibility().iter().filter(|v| **v).count()
+    }
+
+    pub fn highest_scenic_score(&self) -> u64 {
+        self.scenic_scores()
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+// Scenic scores scaled to characters, print it with {:?}
+pub fn heatmap(scores: &Grid2d<u64>) -> Grid2d<char> {
+    let bounds = scores.bounds();
+    let max = scores.iter().copied().max().unwrap_or_default().max(1);
+    let mut map = Grid2d::with_coordinates(bounds, ' ');
+
+    for y in bounds.tl.y..bounds.br.y {
+        for x in bounds.tl.x..bounds.br.x {
+            let score = *scores.index(x, y).unwrap_or(&0);
+            let level = score * (HEAT.len() as u64 - 1) / max;
+            if let Ok(cell) = map.index_mut(x, y) {
+                *cell = HEAT[level as usize];
+            }
+        }
+    }
+    map
+}
+
+pub fn parse(input: &str) -> Result<Forest, AocError> {
+    Forest::from(input)
+}
+
+pub fn part1(forest: &Forest) -> usize {
+    forest.visible_trees()
+}
+
+pub fn part2(forest: &Forest) -> u64 {
+    forest.highest_scenic_score()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/day8.txt");
+
+    #[test]
+    fn viewing_distances() {
+        let forest = parse(EXAMPLE).unwrap();
+        // The middle 5 in the fourth row
+        let up = forest.viewing_distances(Direction::Up);
+        assert_eq!(*up.index(2, 3).unwrap(), 2);
+        let right = forest.viewing_distances(Direction::Right);
+        assert_eq!(*right.index(2, 3).unwrap(), 2);
+    }
+
+    #[test]
+    fn not_square() {
+        let forest = parse("30373\n25512\n").unwrap();
+        assert_eq!(part1(&forest), 10);
+
+        let forest = parse("1\n5\n2\n1\n").unwrap();
+        assert_eq!(part1(&forest), 4);
+        assert_eq!(
+            forest
+                .viewing_distances(Direction::Down)
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![1, 2, 1, 0]
+        );
+        assert_eq!(part2(&forest), 0);
+    }
+
+    #[test]
+    fn heat() {
+        let forest = parse(EXAMPLE).unwrap();
+        let map = heatmap(&forest.scenic_scores());
+        assert_eq!(*map.index(2, 3).unwrap(), '@');
+        assert_eq!(*map.index(0, 0).unwrap(), ' ');
+        assert_eq!(format!("{map:?}").lines().count(), 5);
+    }
+}
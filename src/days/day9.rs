@@ -0,0 +1,234 @@
+use crate::{error::AocError, geometry::Point};
+use std::{
+    collections::HashSet,
+    fmt::{self, Display, Write},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    U,
+    D,
+    L,
+    R,
+}
+
+impl Direction {
+    // x increases to the right and y increases up, as in the puzzle
+    pub fn offset(self) -> Point<i32> {
+        match self {
+            Direction::U => Point::new(0, 1),
+            Direction::D => Point::new(0, -1),
+            Direction::L => Point::new(-1, 0),
+            Direction::R => Point::new(1, 0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub dir: Direction,
+    pub steps: u32,
+}
+
+// Where a knot goes once its leader has moved. It stays put while touching
+// the leader, otherwise it takes one step, diagonal if needed, towards it.
+pub fn follow(leader: Point<i32>, knot: Point<i32>) -> Point<i32> {
+    let d = leader - knot;
+    if d.x.abs() <= 1 && d.y.abs() <= 1 {
+        knot
+    } else {
+        knot + Point::new(d.x.signum(), d.y.signum())
+    }
+}
+
+pub struct Rope {
+    // Head first
+    knots: Vec<Point<i32>>,
+    visited: Vec<HashSet<Point<i32>>>,
+    // Corners of every cell a knot has been in, so frames never shrink
+    min: Point<i32>,
+    max: Point<i32>,
+    trace: Option<String>,
+}
+
+impl Rope {
+    pub fn new(len: usize) -> Rope {
+        assert!(len > 0, "A rope needs at least one knot");
+        let start = Point::new(0, 0);
+        Rope {
+            knots: vec![start; len],
+            visited: vec![HashSet::from([start]); len],
+            min: start,
+            max: start,
+            trace: None,
+        }
+    }
+
+    // Draws a frame after every step, see trace()
+    pub fn with_trace(mut self) -> Rope {
+        self.trace = Some(String::new());
+        self
+    }
+
+    pub fn knots(&self) -> &[Point<i32>] {
+        &self.knots
+    }
+
+    // Cells knot has been in, 0 is the head
+    pub fn visited(&self, knot: usize) -> &HashSet<Point<i32>> {
+        &self.visited[knot]
+    }
+
+    pub fn tail_visited(&self) -> &HashSet<Point<i32>> {
+        &self.visited[self.knots.len() - 1]
+    }
+
+    pub fn trace(&self) -> Option<&str> {
+        self.trace.as_deref()
+    }
+
+    pub fn step(&mut self, dir: Direction) {
+        self.knots[0] = self.knots[0] + dir.offset();
+        for i in 1..self.knots.len() {
+            let moved = follow(self.knots[i - 1], self.knots[i]);
+            if moved == self.knots[i] {
+                // The knots behind cannot move either
+                break;
+            }
+            self.knots[i] = moved;
+        }
+
+        for (knot, visited) in self.knots.iter().zip(self.visited.iter_mut()) {
+            visited.insert(*knot);
+            self.min = Point::new(self.min.x.min(knot.x), self.min.y.min(knot.y));
+            self.max = Point::new(self.max.x.max(knot.x), self.max.y.max(knot.y));
+        }
+    }
+
+    pub fn apply(&mut self, m: &Move) {
+        if let Some(trace) = self.trace.as_mut() {
+            let _ = writeln!(trace, "== {:?} {} ==\n", m.dir, m.steps);
+        }
+
+        for _ in 0..m.steps {
+            self.step(m.dir);
+
+            if let Some(mut trace) = self.trace.take() {
+                let _ = self.draw(&mut trace).and_then(|_| writeln!(trace));
+                self.trace = Some(trace);
+            }
+        }
+    }
+
+    fn label(&self, knot: usize) -> char {
+        match (knot, self.knots.len()) {
+            (0, _) => 'H',
+            (_, 2) => 'T',
+            _ => char::from_digit(knot as u32 % 10, 10).unwrap_or('?'),
+        }
+    }
+
+    // The puzzle's notation: H and the knot numbers, s for the start and #
+    // for cells the tail has visited
+    pub fn draw<W: Write>(&self, out: &mut W) -> fmt::Result {
+        let start = Point::new(0, 0);
+        for y in (self.min.y..=self.max.y).rev() {
+            for x in self.min.x..=self.max.x {
+                let p = Point::new(x, y);
+                let c = match self.knots.iter().position(|k| *k == p) {
+                    Some(knot) => self.label(knot),
+                    None if p == start => 's',
+                    None if self.tail_visited().contains(&p) => '#',
+                    None => '.',
+                };
+                out.write_char(c)?;
+            }
+            writeln!(out)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.draw(f)
+    }
+}
+
+// R 4
+fn parse_move(line: &str) -> Option<Move> {
+    let (dir, steps) = line.split_once(' ')?;
+    let dir = match dir {
+        "U" => Direction::U,
+        "D" => Direction::D,
+        "L" => Direction::L,
+        "R" => Direction::R,
+        _ => return None,
+    };
+
+    Some(Move {
+        dir,
+        steps: steps.trim().parse().ok()?,
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<Move>, AocError> {
+    input
+        .lines()
+        .map(|l| {
+            parse_move(l).ok_or_else(|| AocError::parse(input, l, "Expected a move like \"R 4\""))
+        })
+        .collect()
+}
+
+pub fn simulate(moves: &[Move], len: usize) -> Rope {
+    let mut rope = Rope::new(len);
+    for m in moves {
+        rope.apply(m);
+    }
+    rope
+}
+
+pub fn part1(moves: &[Move]) -> usize {
+    simulate(moves, 2).tail_visited().len()
+}
+
+pub fn part2(moves: &[Move]) -> usize {
+    simulate(moves, 10).tail_visited().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../fixtures/day9.txt");
+
+    #[test]
+    fn larger_example() {
+        let larger = parse("R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20\n").unwrap();
+        assert_eq!(part2(&larger), 36);
+        assert!(parse("S 1").is_err());
+    }
+
+    #[test]
+    fn knots() {
+        assert_eq!(follow(Point::new(2, 1), Point::new(0, 0)), Point::new(1, 1));
+        assert_eq!(follow(Point::new(1, 1), Point::new(0, 0)), Point::new(0, 0));
+
+        // The head visits every cell the first knot does, and more
+        let rope = simulate(&parse(EXAMPLE).unwrap(), 3);
+        assert!(rope.visited(1).is_subset(rope.visited(0)));
+        assert!(rope.visited(0).len() > rope.visited(1).len());
+    }
+
+    #[test]
+    fn trace() {
+        let mut rope = Rope::new(2).with_trace();
+        rope.apply(&Move {
+            dir: Direction::R,
+            steps: 2,
+        });
+        assert_eq!(rope.trace(), Some("== R 2 ==\n\nTH\n\nsTH\n\n"));
+        assert_eq!(rope.to_string(), "sTH\n");
+    }
+}
@@ -0,0 +1,131 @@
+use crate::error::AocError;
+use std::{any::Any, collections::HashMap};
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+// Named puzzle parameters, e.g. the row to check on day 15. The examples use
+// different values than the real input, so solvers take these with a default.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Params {
+    values: HashMap<String, i64>,
+}
+
+impl Params {
+    pub fn new() -> Params {
+        Params::default()
+    }
+
+    pub fn set(&mut self, key: &str, value: i64) {
+        self.values.insert(String::from(key), value);
+    }
+
+    pub fn get_or(&self, key: &str, default: i64) -> i64 {
+        *self.values.get(key).unwrap_or(&default)
+    }
+}
+
+// Parsed puzzle input, each day has its own type
+pub type Parsed = Box<dyn Any>;
+
+pub struct Day {
+    pub day: u8,
+    pub parse: fn(&str) -> Result<Parsed, AocError>,
+    pub part1: fn(&Parsed, &Params) -> Result<String, AocError>,
+    pub part2: fn(&Parsed, &Params) -> Result<String, AocError>,
+}
+
+// Parts return a plain answer, or a Result when there may be no solution
+pub trait Answer {
+    fn answer(self) -> Result<String, AocError>;
+}
+
+macro_rules! answer {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {
+            fn answer(self) -> Result<String, AocError> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+answer!(i32, i64, u32, u64, usize, String);
+
+impl<T: Answer> Answer for Result<T, AocError> {
+    fn answer(self) -> Result<String, AocError> {
+        self?.answer()
+    }
+}
+
+// Recover the type returned by a day's parse function
+fn downcast<P: 'static, F: Fn(&str) -> Result<P, AocError>>(parsed: &Parsed, _parse: F) -> &P {
+    parsed
+        .downcast_ref::<P>()
+        .expect("Parsed input from a different day")
+}
+
+macro_rules! day {
+    ($day:expr, $module:ident) => {
+        Day {
+            day: $day,
+            parse: |input| Ok(Box::new($module::parse(input)?)),
+            part1: |parsed, _| {
+                let parsed = downcast(parsed, $module::parse);
+                $module::part1(parsed).answer()
+            },
+            part2: |parsed, _| {
+                let parsed = downcast(parsed, $module::parse);
+                $module::part2(parsed).answer()
+            },
+        }
+    };
+}
+
+pub static DAYS: &[Day] = &[
+    day!(1, day1),
+    day!(2, day2),
+    day!(3, day3),
+    day!(4, day4),
+    day!(5, day5),
+    day!(6, day6),
+    day!(7, day7),
+    day!(8, day8),
+    day!(9, day9),
+    day!(10, day10),
+    day!(11, day11),
+    day!(12, day12),
+    day!(13, day13),
+    day!(14, day14),
+    Day {
+        day: 15,
+        parse: |input| Ok(Box::new(day15::parse(input)?)),
+        part1: |parsed, params| {
+            let parsed = downcast(parsed, day15::parse);
+            let row = params.get_or("row", day15::ROW as i64) as i32;
+            day15::part1(parsed, row).answer()
+        },
+        part2: |parsed, params| {
+            let parsed = downcast(parsed, day15::parse);
+            let max = params.get_or("search_max", day15::SEARCH_MAX as i64) as i32;
+            day15::part2(parsed, max).answer()
+        },
+    },
+];
+
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
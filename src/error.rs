@@ -0,0 +1,166 @@
+use std::{error::Error, fmt, io, path::Path};
+
+// Longest excerpt of the offending input kept in a parse error
+const EXCERPT_LEN: usize = 40;
+
+pub enum AocError {
+    Io(io::Error),
+    // line is 1-based, 0 when the text could not be found in the input
+    Parse {
+        line: usize,
+        excerpt: String,
+        reason: String,
+    },
+    Geometry(String),
+    NoSolution(String),
+    Other(String),
+}
+
+fn excerpt(line: &str) -> String {
+    let line = line.trim();
+    match line.char_indices().nth(EXCERPT_LEN) {
+        Some((i, _)) => format!("{}...", &line[..i]),
+        None => String::from(line),
+    }
+}
+
+impl AocError {
+    // at is the part of input that could not be parsed, usually a line or a
+    // slice of one. The error reports the whole line it starts on, which is
+    // why parsers of part of a line, like Expr::parse_in, take the input too.
+    pub fn parse(input: &str, at: &str, reason: &str) -> AocError {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|o| *o + at.len() <= input.len())
+            .or_else(|| input.find(at).filter(|_| !at.is_empty()));
+
+        let (line, text) = match offset {
+            Some(o) => {
+                let start = input[..o].rfind('\n').map_or(0, |i| i + 1);
+                let end = input[o..].find('\n').map_or(input.len(), |i| o + i);
+                (input[..o].matches('\n').count() + 1, &input[start..end])
+            }
+            None => (0, at),
+        };
+
+        AocError::at_line(line, text, reason)
+    }
+
+    // For readers that count lines themselves, e.g. when streaming
+    pub fn at_line(line: usize, text: &str, reason: &str) -> AocError {
+        AocError::Parse {
+            line,
+            excerpt: excerpt(text),
+            reason: String::from(reason),
+        }
+    }
+
+    // toml reports a 0-based line and column
+    pub fn toml(input: &str, e: toml::de::Error) -> AocError {
+        let line = e.line_col().map_or(0, |(l, _)| l + 1);
+        let text = line.checked_sub(1).and_then(|l| input.lines().nth(l));
+
+        AocError::at_line(line, text.unwrap_or_default(), &e.to_string())
+    }
+
+    pub fn io(path: &Path, e: io::Error) -> AocError {
+        AocError::Io(io::Error::new(e.kind(), format!("{}: {e}", path.display())))
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io(e) => write!(f, "{e}"),
+            AocError::Parse {
+                line: 0,
+                excerpt,
+                reason,
+            } => write!(f, "Parse error: {reason} in {excerpt:?}"),
+            AocError::Parse {
+                line,
+                excerpt,
+                reason,
+            } => write!(f, "Parse error on line {line}: {reason} in {excerpt:?}"),
+            AocError::Geometry(s) => write!(f, "Geometry error: {s}"),
+            AocError::NoSolution(s) => write!(f, "No solution found: {s}"),
+            AocError::Other(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+// Binaries return this from main, which prints the Debug form
+impl fmt::Debug for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> AocError {
+        AocError::Io(e)
+    }
+}
+
+impl From<String> for AocError {
+    fn from(s: String) -> AocError {
+        AocError::Other(s)
+    }
+}
+
+impl From<&str> for AocError {
+    fn from(s: &str) -> AocError {
+        AocError::Other(String::from(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_line() {
+        let input = "1,2\n3;4\n5,6\n";
+        let line = input.lines().nth(1).unwrap();
+        let e = AocError::parse(input, &line[2..], "expected a comma");
+
+        assert!(matches!(&e, AocError::Parse { line: 2, excerpt, .. } if excerpt == "3;4"));
+        assert_eq!(
+            e.to_string(),
+            "Parse error on line 2: expected a comma in \"3;4\""
+        );
+
+        // Text that is not a slice of the input is searched for
+        let e = AocError::parse(input, &String::from("5,6"), "bad");
+        assert!(matches!(e, AocError::Parse { line: 3, .. }));
+        let e = AocError::parse(input, "7,8", "bad");
+        assert_eq!(e.to_string(), "Parse error: bad in \"7,8\"");
+    }
+
+    #[test]
+    fn long_excerpt() {
+        let input = "x".repeat(100);
+        let e = AocError::parse(&input, &input, "bad");
+        assert!(e.to_string().ends_with(&format!("{}...\"", "x".repeat(40))));
+    }
+
+    #[test]
+    fn conversions() {
+        let e = AocError::from(io::Error::new(io::ErrorKind::NotFound, "gone"));
+        assert!(e.source().is_some());
+        assert_eq!(AocError::from("oops").to_string(), "oops");
+
+        let input = "a = 1\nb = = 2\n";
+        let e = AocError::toml(input, input.parse::<toml::Value>().unwrap_err());
+        assert!(matches!(&e, AocError::Parse { line: 2, excerpt, .. } if excerpt == "b = = 2"));
+    }
+}
@@ -0,0 +1,228 @@
+use crate::error::AocError;
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, NumCast};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl BinOp {
+    fn symbol(self) -> char {
+        match self {
+            BinOp::Add => '+',
+            BinOp::Sub => '-',
+            BinOp::Mul => '*',
+            BinOp::Div => '/',
+        }
+    }
+
+    fn precedence(self) -> u8 {
+        match self {
+            BinOp::Add | BinOp::Sub => 1,
+            BinOp::Mul | BinOp::Div => 2,
+        }
+    }
+}
+
+// An arithmetic expression in one variable, e.g. old * (old + 3)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Old,
+    Literal(u64),
+    Binary(Box<Expr>, BinOp, Box<Expr>),
+}
+
+impl Expr {
+    pub fn parse(s: &str) -> Result<Expr, AocError> {
+        Expr::parse_in(s, s)
+    }
+
+    pub fn parse_in(input: &str, s: &str) -> Result<Expr, AocError> {
+        let mut parser = Parser {
+            input,
+            s,
+            tokens: tokenize(s),
+            pos: 0,
+        };
+        let expr = parser.sum()?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some(_) => Err(parser.error("Expected an operator")),
+        }
+    }
+
+    // Integer arithmetic in T, division rounds towards zero. Fails on
+    // overflow, division by zero and literals that do not fit in T.
+    pub fn eval<T>(&self, old: T) -> Result<T, AocError>
+    where
+        T: Copy + NumCast + CheckedAdd + CheckedSub + CheckedMul + CheckedDiv + fmt::Display,
+    {
+        let value = match self {
+            Expr::Old => Some(old),
+            Expr::Literal(n) => T::from(*n),
+            Expr::Binary(lhs, op, rhs) => {
+                let (a, b) = (lhs.eval(old)?, rhs.eval(old)?);
+                match op {
+                    BinOp::Add => a.checked_add(&b),
+                    BinOp::Sub => a.checked_sub(&b),
+                    BinOp::Mul => a.checked_mul(&b),
+                    BinOp::Div => a.checked_div(&b),
+                }
+            }
+        };
+
+        value.ok_or(AocError::Other(format!(
+            "{self} cannot be evaluated for old = {old}"
+        )))
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, parent: u8) -> fmt::Result {
+        match self {
+            Expr::Old => write!(f, "old"),
+            Expr::Literal(n) => write!(f, "{n}"),
+            Expr::Binary(lhs, op, rhs) => {
+                let p = op.precedence();
+                if p < parent {
+                    write!(f, "(")?;
+                }
+                lhs.write(f, p)?;
+                write!(f, " {} ", op.symbol())?;
+                // a - (b - c) and a / (b * c) need their brackets
+                rhs.write(f, p + 1)?;
+                if p < parent {
+                    write!(f, ")")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+// Only as many brackets as needed
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+// Tokens are slices of the expression, so errors can point at them
+fn tokenize(s: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut rest = s.trim_start();
+
+    while let Some(c) = rest.chars().next() {
+        let len = if c.is_ascii_alphanumeric() {
+            rest.find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(rest.len())
+        } else {
+            c.len_utf8()
+        };
+        tokens.push(&rest[..len]);
+        rest = rest[len..].trim_start();
+    }
+
+    tokens
+}
+
+// Recursive descent, one function per precedence level
+struct Parser<'a> {
+    input: &'a str,
+    s: &'a str,
+    tokens: Vec<&'a str>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a str> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn error(&self, reason: &str) -> AocError {
+        AocError::parse(self.input, self.peek().unwrap_or(self.s), reason)
+    }
+
+    fn binary(
+        &mut self,
+        ops: &[BinOp],
+        next: fn(&mut Self) -> Result<Expr, AocError>,
+    ) -> Result<Expr, AocError> {
+        let mut expr = next(self)?;
+        while let Some(op) = self
+            .peek()
+            .and_then(|t| ops.iter().find(|op| t == op.symbol().to_string()))
+        {
+            self.pos += 1;
+            expr = Expr::Binary(Box::new(expr), *op, Box::new(next(self)?));
+        }
+        Ok(expr)
+    }
+
+    // a + b - c
+    fn sum(&mut self) -> Result<Expr, AocError> {
+        self.binary(&[BinOp::Add, BinOp::Sub], Parser::product)
+    }
+
+    // a * b / c
+    fn product(&mut self) -> Result<Expr, AocError> {
+        self.binary(&[BinOp::Mul, BinOp::Div], Parser::operand)
+    }
+
+    // old, 19 or (...)
+    fn operand(&mut self) -> Result<Expr, AocError> {
+        let token = self.peek();
+        let expr = match token {
+            Some("old") => Expr::Old,
+            Some("(") => {
+                self.pos += 1;
+                let expr = self.sum()?;
+                if self.peek() != Some(")") {
+                    return Err(self.error("Expected )"));
+                }
+                expr
+            }
+            Some(t) if t.bytes().all(|b| b.is_ascii_digit()) => {
+                Expr::Literal(t.parse().map_err(|_| self.error("Number is too large"))?)
+            }
+            _ => return Err(self.error("Expected old, a number or (")),
+        };
+
+        self.pos += 1;
+        Ok(expr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn precedence() {
+        let e = Expr::parse("old + 2 * (old - 1) / 3").unwrap();
+        assert_eq!(e.to_string(), "old + 2 * (old - 1) / 3");
+        assert_eq!(e.eval(7i64).unwrap(), 11);
+        assert_eq!(e.eval(7u8).unwrap(), 11);
+
+        // Left associative, so the brackets on the right stay
+        let e = Expr::parse("10 - (old - 2) - old").unwrap();
+        assert_eq!(e.to_string(), "10 - (old - 2) - old");
+        assert_eq!(e.eval(3i32).unwrap(), 6);
+        assert_eq!(Expr::parse("((old))*old").unwrap().to_string(), "old * old");
+    }
+
+    #[test]
+    fn errors() {
+        assert!(Expr::parse("old ^ 2").is_err());
+        assert!(Expr::parse("old * (2 + 1").is_err());
+        assert!(Expr::parse("").is_err());
+        assert!(Expr::parse("new + 1").is_err());
+
+        let e = Expr::parse("old * old").unwrap();
+        assert!(e.eval(16u8).is_err());
+        assert!(Expr::parse("old / (old - 1)").unwrap().eval(1u32).is_err());
+        assert!(Expr::parse("300").unwrap().eval(0u8).is_err());
+    }
+}
@@ -0,0 +1,33 @@
+use crate::error::AocError;
+use std::{fs, io, path::Path};
+
+// For files such as answers.toml that are only created on first save, so a
+// missing file reads as T::default()
+pub fn read_or_default<T, F>(path: &Path, from: F) -> Result<T, AocError>
+where
+    T: Default,
+    F: FnOnce(&str) -> Result<T, AocError>,
+{
+    match fs::read_to_string(path) {
+        Ok(s) => from(&s),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(e) => Err(AocError::io(path, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_file() {
+        let path = std::env::temp_dir().join(format!("aoc2022-{}-files", std::process::id()));
+        let read = |p: &Path| read_or_default(p, |s| Ok(String::from(s)));
+        let _ = fs::remove_file(&path);
+        assert_eq!(read(&path).unwrap(), "");
+
+        fs::write(&path, "part1 = \"1\"").unwrap();
+        assert_eq!(read(&path).unwrap(), "part1 = \"1\"");
+        fs::remove_file(&path).unwrap();
+    }
+}
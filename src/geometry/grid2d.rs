@@ -1,6 +1,9 @@
 use std::{fmt::Debug, ops::Rem, vec};
 
-use crate::geometry::{BoundingBox, Point, Rectangle};
+use crate::{
+    error::AocError,
+    geometry::{BoundingBox, Point, Rectangle},
+};
 
 pub struct GridRowIterator<'a, T: Copy + Clone> {
     grid: &'a Grid2d<T>,
@@ -36,18 +39,29 @@ impl<T: Copy + Clone> Grid2d<T> {
         }
     }
 
-    pub fn index(&self, x: i32, y: i32) -> Result<&T, ()> {
-        if !self.in_bounds(&Point::new(x, y)) {
-            return Err(());
+    fn check_bounds(&self, x: i32, y: i32) -> Result<(), AocError> {
+        if self.in_bounds(&Point::new(x, y)) {
+            Ok(())
+        } else {
+            Err(AocError::Geometry(format!(
+                "{x},{y} is outside the grid {:?}",
+                self.bounds()
+            )))
         }
+    }
+
+    pub fn index(&self, x: i32, y: i32) -> Result<&T, AocError> {
+        self.check_bounds(x, y)?;
         Ok(&self.grid[(y - self.coord_top_left.y) as usize][(x - self.coord_top_left.x) as usize])
     }
 
-    pub fn index_mut(&mut self, x: i32, y: i32) -> &mut T {
-        &mut self.grid[(y - self.coord_top_left.y) as usize][(x - self.coord_top_left.x) as usize]
+    pub fn index_mut(&mut self, x: i32, y: i32) -> Result<&mut T, AocError> {
+        self.check_bounds(x, y)?;
+        Ok(&mut self.grid[(y - self.coord_top_left.y) as usize]
+            [(x - self.coord_top_left.x) as usize])
     }
 
-    pub fn row_iter(&self, row: i32) -> GridRowIterator<T> {
+    pub fn row_iter(&self, row: i32) -> GridRowIterator<'_, T> {
         GridRowIterator {
             grid: self,
             row,
@@ -55,6 +69,11 @@ impl<T: Copy + Clone> Grid2d<T> {
         }
     }
 
+    // Every cell, row by row
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.grid.iter().flatten()
+    }
+
     pub fn set_or_insert(&mut self, x: i32, y: i32, value: T) {
         let p = Point::new(x, y);
         let bounds = self.bounds();
@@ -100,7 +119,7 @@ impl<T: Copy + Clone> Grid2d<T> {
 
                 for i in 0..bounds.height() {
                     for _ in 0..num_to_add {
-                        self.grid[i as usize].push(self.default.clone());
+                        self.grid[i as usize].push(self.default);
                     }
                 }
             }
@@ -147,7 +166,7 @@ impl<T: Clone + Copy> Grid2d<T> {
 
 impl<T: Copy> BoundingBox<i32> for Grid2d<T> {
     fn bounds(&self) -> Rectangle<i32> {
-        let x_len = if self.grid.len() == 0 {
+        let x_len = if self.grid.is_empty() {
             0
         } else {
             self.grid[0].len()
@@ -163,9 +182,9 @@ fn get_digit(x: i32, digit: usize) -> char {
     let arr = x.abs().to_string().chars().rev().collect::<Vec<char>>();
 
     if digit >= arr.len() {
-        return '0';
+        '0'
     } else {
-        return arr[digit];
+        arr[digit]
     }
 }
 
@@ -178,34 +197,34 @@ impl<T: ToString + Copy> Debug for Grid2d<T> {
         if f.alternate() {
             // Print header
             for i in (0..pad_height + 1).rev() {
-                print!("{:width$}", " ", width = width);
+                write!(f, "{:width$}", " ", width = width)?;
                 for j in bounds.tl.x..bounds.br.x {
                     if j.rem(5) == 0 {
                         let digit = get_digit(j, i);
                         if i == pad_height && j.is_negative() {
-                            print!("-");
+                            write!(f, "-")?;
                         } else if digit == '0' && i != 0 {
-                            print!(" ");
+                            write!(f, " ")?;
                         } else {
-                            print!("{digit}");
+                            write!(f, "{digit}")?;
                         }
                     } else {
-                        print!(" ");
+                        write!(f, " ")?;
                     }
                 }
-                println!("");
+                writeln!(f)?;
             }
         }
 
         for y in bounds.tl.y..bounds.br.y {
             if f.alternate() {
-                print!("{:<width$}", y, width = width);
+                write!(f, "{:<width$}", y, width = width)?;
             }
 
             for x in bounds.tl.x..bounds.br.x {
-                print!("{}", self.index(x, y).unwrap().to_string());
+                write!(f, "{}", self.index(x, y).unwrap().to_string())?;
             }
-            println!("");
+            writeln!(f)?;
         }
 
         Ok(())
@@ -245,6 +264,9 @@ mod tests {
         assert_eq!(*grid.index(-8, -3).unwrap(), 'M');
         assert_eq!(*grid.index(-2, -1).unwrap(), '.');
         assert_eq!(grid.coord_top_left, Point::new(-8, -3));
-        assert_eq!(grid.index(-9, -4), Err(()));
+        assert!(matches!(grid.index(-9, -4), Err(AocError::Geometry(_))));
+        *grid.index_mut(-2, -1).unwrap() = 'm';
+        assert_eq!(*grid.index(-2, -1).unwrap(), 'm');
+        assert!(grid.index_mut(16, 0).is_err());
     }
 }
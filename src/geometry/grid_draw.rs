@@ -1,6 +1,7 @@
 use num_traits::PrimInt;
+use std::fmt;
 
-use crate::geometry::{Circle, Grid2d, Line, Num};
+use crate::geometry::{BoundingBox, Circle, Grid2d, Line, Num, Point};
 
 pub fn draw_line<B: Copy + Clone, T: Num + PrimInt, F>(grid: &mut Grid2d<B>, line: &Line<T>, f: F)
 where
@@ -44,3 +45,160 @@ pub fn draw_manhattan_circle<B: Copy + Clone, T: Num + PrimInt, F>(
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Marker {
+    // ^ v < > towards the next point, the last point is left as it was
+    Arrows,
+    // The last digit of how many steps in each point is
+    Steps,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Colour {
+    pub const ALL: [Colour; 6] = [
+        Colour::Red,
+        Colour::Green,
+        Colour::Yellow,
+        Colour::Blue,
+        Colour::Magenta,
+        Colour::Cyan,
+    ];
+
+    fn ansi(self) -> u8 {
+        31 + self as u8
+    }
+}
+
+// The character each point of a path is drawn with
+pub fn path_marks(
+    path: &[Point<i32>],
+    marker: Marker,
+) -> impl Iterator<Item = (Point<i32>, char)> + '_ {
+    let marks = path.iter().enumerate().map(move |(i, p)| {
+        let mark = match marker {
+            Marker::Arrows => path.get(i + 1).map(|next| arrow(*next - *p)),
+            Marker::Steps => char::from_digit((i % 10) as u32, 10),
+        };
+        (*p, mark)
+    });
+    marks.filter_map(|(p, mark)| Some((p, mark?)))
+}
+
+fn arrow(d: Point<i32>) -> char {
+    match (d.x.signum(), d.y.signum()) {
+        (0, -1) => '^',
+        (0, 1) => 'v',
+        (-1, 0) => '<',
+        (1, 0) => '>',
+        // Not a single step up, down, left or right
+        _ => '*',
+    }
+}
+
+pub fn draw_path(grid: &mut Grid2d<char>, path: &[Point<i32>], marker: Marker) {
+    for (p, mark) in path_marks(path, marker) {
+        grid.set_or_insert(p.x, p.y, mark);
+    }
+}
+
+// Several paths drawn over the same grid, each in its own colour. Later
+// paths are drawn on top of earlier ones.
+pub struct Overlay {
+    cells: Grid2d<(char, Option<Colour>)>,
+}
+
+impl Overlay {
+    pub fn new(base: &Grid2d<char>) -> Overlay {
+        let bounds = base.bounds();
+        let mut cells = Grid2d::with_coordinates(bounds, (base.default, None));
+        for y in bounds.tl.y..bounds.br.y {
+            for x in bounds.tl.x..bounds.br.x {
+                if let (Ok(c), Ok(cell)) = (base.index(x, y), cells.index_mut(x, y)) {
+                    cell.0 = *c;
+                }
+            }
+        }
+        Overlay { cells }
+    }
+
+    pub fn path(&mut self, path: &[Point<i32>], marker: Marker, colour: Colour) -> &mut Overlay {
+        for (p, mark) in path_marks(path, marker) {
+            self.cells.set_or_insert(p.x, p.y, (mark, Some(colour)));
+        }
+        self
+    }
+
+    // Each path gets the next colour in Colour::ALL
+    pub fn paths(&mut self, paths: &[Vec<Point<i32>>], marker: Marker) -> &mut Overlay {
+        for (path, colour) in paths.iter().zip(Colour::ALL.iter().cycle()) {
+            self.path(path, marker, *colour);
+        }
+        self
+    }
+
+    // The drawing without colours
+    pub fn chars(&self) -> Grid2d<char> {
+        let bounds = self.cells.bounds();
+        let mut grid = Grid2d::with_coordinates(bounds, self.cells.default.0);
+        for y in bounds.tl.y..bounds.br.y {
+            for x in bounds.tl.x..bounds.br.x {
+                if let (Ok(cell), Ok(c)) = (self.cells.index(x, y), grid.index_mut(x, y)) {
+                    *c = cell.0;
+                }
+            }
+        }
+        grid
+    }
+}
+
+// Colours are ANSI escape codes
+impl fmt::Display for Overlay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bounds = self.cells.bounds();
+        for y in bounds.tl.y..bounds.br.y {
+            for x in bounds.tl.x..bounds.br.x {
+                match self.cells.index(x, y) {
+                    Ok((c, Some(colour))) => write!(f, "\x1b[1;{}m{c}\x1b[0m", colour.ansi())?,
+                    Ok((c, None)) => write!(f, "{c}")?,
+                    Err(_) => write!(f, " ")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths() {
+        let path = [(0, 0), (1, 0), (1, 1), (0, 1), (0, 2)].map(|(x, y)| Point::new(x, y));
+        let mut grid = Grid2d::with_size(3, 3, '.');
+        draw_path(&mut grid, &path, Marker::Arrows);
+        assert_eq!(format!("{grid:?}"), ">v.\nv<.\n...\n");
+
+        let mut grid = Grid2d::with_size(3, 3, '.');
+        draw_path(&mut grid, &path, Marker::Steps);
+        assert_eq!(format!("{grid:?}"), "01.\n32.\n4..\n");
+
+        let mut overlay = Overlay::new(&Grid2d::with_size(3, 3, '.'));
+        overlay.paths(&[path.to_vec(), vec![Point::new(2, 2)]], Marker::Steps);
+        assert_eq!(format!("{:?}", overlay.chars()), "01.\n32.\n4.0\n");
+        let drawn = overlay.to_string();
+        assert!(drawn.starts_with("\x1b[1;31m0\x1b[0m"));
+        assert!(drawn.ends_with(".\x1b[1;32m0\x1b[0m\n"));
+    }
+}
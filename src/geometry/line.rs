@@ -1,9 +1,5 @@
 use crate::geometry::{Num, Point};
-use num_traits::{PrimInt, Signed};
-use std::{
-    fmt::Debug,
-    ops::{Add, AddAssign},
-};
+use std::{fmt::Debug, ops::AddAssign};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Line<T>
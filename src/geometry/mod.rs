@@ -6,8 +6,6 @@ pub mod line;
 pub mod point;
 pub mod rectangle;
 
-// TODO: Better errors
-
 use num_traits::NumOps;
 use std::{fmt::Debug, str::FromStr};
 pub trait Num: NumOps + Debug + Copy + FromStr + Sized + PartialOrd + Ord + Eq + PartialEq {}
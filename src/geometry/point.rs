@@ -1,8 +1,11 @@
-use crate::geometry::Num;
+use crate::{error::AocError, geometry::Num};
 use num_traits::{PrimInt, Signed};
-use std::{fmt::Debug, ops::Add};
+use std::{
+    fmt::Debug,
+    ops::{Add, Sub},
+};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Point<T>
 where
     T: Num,
@@ -16,14 +19,13 @@ where
     T: Num,
 {
     // ###,###
-    pub fn from(s: &str) -> Result<Point<T>, &'static str> {
-        let vals = s.split(",").collect::<Vec<&str>>();
-
-        Ok(Point {
-            // Remove dependency on <T as FromStr>::Err
-            x: vals[0].parse::<T>().map_err(|_| "Unable to parse string")?,
-            y: vals[1].parse::<T>().map_err(|_| "Unable to parse string")?,
-        })
+    pub fn from(s: &str) -> Result<Point<T>, AocError> {
+        // Remove dependency on <T as FromStr>::Err
+        let parse = |v: &str| v.trim().parse::<T>().ok();
+
+        s.split_once(',')
+            .and_then(|(x, y)| Some(Point::new(parse(x)?, parse(y)?)))
+            .ok_or_else(|| AocError::parse(s, s, "Expected a point like 498,4"))
     }
 
     pub fn new(x: T, y: T) -> Point<T> {
@@ -59,3 +61,23 @@ impl<T: Num> Add for Point<T> {
         Point::new(self.x + rhs.x, self.y + rhs.y)
     }
 }
+
+impl<T: Num> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Point::<i32>::from("498,-4").unwrap(), Point::new(498, -4));
+        assert!(Point::<i32>::from("498").is_err());
+        assert!(Point::<i32>::from("498,x").is_err());
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{client::Client, error::AocError};
+use std::{
+    fs::{self, File},
+    io::{self, BufRead, BufReader},
+    ops::Add,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+// Downloaded inputs are cached here as dayN.txt
+pub const INPUT_DIR: &str = "inputs";
+
+// Where the binaries used to read their input from, still used when present
+pub const LOCAL_INPUT: &str = "input.txt";
+
+pub fn path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{day}.txt"))
+}
+
+// Read ./input.txt or the cached input, downloading it first when missing
+pub fn load(day: u8) -> Result<String, AocError> {
+    let local = Path::new(LOCAL_INPUT);
+    if local.exists() {
+        return fs::read_to_string(local).map_err(|e| AocError::io(local, e));
+    }
+    cached(day)
+}
+
+// Skips ./input.txt, for running several days at once
+pub fn cached(day: u8) -> Result<String, AocError> {
+    load_with(&Client::from_env(), Path::new(INPUT_DIR), day)
+}
+
+pub fn load_with(client: &Client, dir: &Path, day: u8) -> Result<String, AocError> {
+    let path = path(dir, day);
+
+    match fs::read_to_string(&path) {
+        Ok(input) => return Ok(input),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(AocError::io(&path, e)),
+    }
+
+    let input = client.input(day)?;
+
+    fs::create_dir_all(dir)
+        .and_then(|_| fs::write(&path, &input))
+        .map_err(|e| AocError::io(&path, e))?;
+
+    Ok(input)
+}
+
+// Open ./input.txt or the cached input to stream it, downloading it first
+// when missing
+pub fn open(day: u8) -> Result<BufReader<File>, AocError> {
+    let local = Path::new(LOCAL_INPUT);
+    if local.exists() {
+        return File::open(local)
+            .map(BufReader::new)
+            .map_err(|e| AocError::io(local, e));
+    }
+    open_with(&Client::from_env(), Path::new(INPUT_DIR), day)
+}
+
+pub fn open_with(client: &Client, dir: &Path, day: u8) -> Result<BufReader<File>, AocError> {
+    let path = path(dir, day);
+    if !path.exists() {
+        load_with(client, dir, day)?;
+    }
+
+    File::open(&path)
+        .map(BufReader::new)
+        .map_err(|e| AocError::io(&path, e))
+}
+
+// Blank-line separated groups of lines, each folded into an accumulator one
+// line at a time. Only the current line is held in memory.
+pub struct Groups<R, A, F> {
+    lines: io::Lines<R>,
+    line: usize,
+    init: A,
+    fold: F,
+}
+
+pub fn groups<R, A, F>(reader: R, init: A, fold: F) -> Groups<R, A, F>
+where
+    R: BufRead,
+    A: Clone,
+    F: FnMut(A, &str) -> Result<A, String>,
+{
+    Groups {
+        lines: reader.lines(),
+        line: 0,
+        init,
+        fold,
+    }
+}
+
+impl<R, A, F> Iterator for Groups<R, A, F>
+where
+    R: BufRead,
+    A: Clone,
+    F: FnMut(A, &str) -> Result<A, String>,
+{
+    type Item = Result<A, AocError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut acc = None;
+
+        for l in self.lines.by_ref() {
+            self.line += 1;
+            let l = match l {
+                Ok(l) => l,
+                Err(e) => return Some(Err(e.into())),
+            };
+
+            // Runs of blank lines do not make empty groups
+            if l.trim().is_empty() {
+                if acc.is_some() {
+                    break;
+                }
+                continue;
+            }
+
+            let current = acc.take().unwrap_or_else(|| self.init.clone());
+            match (self.fold)(current, &l) {
+                Ok(a) => acc = Some(a),
+                Err(reason) => return Some(Err(AocError::at_line(self.line, &l, &reason))),
+            }
+        }
+
+        acc.map(Ok)
+    }
+}
+
+type Fold<T> = fn(T, &str) -> Result<T, String>;
+
+// Sum of the numbers in each group, e.g. the calories each elf carries
+pub fn group_sums<R, T>(reader: R) -> Groups<R, T, Fold<T>>
+where
+    R: BufRead,
+    T: FromStr + Add<Output = T> + Default + Clone,
+{
+    groups(reader, T::default(), |sum, l| {
+        l.trim()
+            .parse::<T>()
+            .map(|v| sum + v)
+            .map_err(|_| String::from("Expected a number"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sums() {
+        let input = "1000\n2000\n\n\n4000\n\n5000\n6000\n";
+        let sums = group_sums::<_, i32>(input.as_bytes())
+            .collect::<Result<Vec<i32>, AocError>>()
+            .unwrap();
+        assert_eq!(sums, vec![3000, 4000, 11000]);
+
+        let err = group_sums::<_, i32>("1\n\n2\nx\n".as_bytes())
+            .find_map(|s| s.err())
+            .unwrap();
+        assert!(matches!(err, AocError::Parse { line: 4, .. }), "{err}");
+    }
+
+    #[test]
+    fn other_reducers() {
+        let input = "ab\ncd\n\nefg";
+        let lengths = groups(input.as_bytes(), Vec::new(), |mut lens, l| {
+            lens.push(l.len());
+            Ok(lens)
+        })
+        .collect::<Result<Vec<Vec<usize>>, AocError>>()
+        .unwrap();
+        assert_eq!(lengths, vec![vec![2, 2], vec![3]]);
+    }
+}
@@ -0,0 +1,230 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+    iter::Map,
+};
+
+// Stable stand-ins for the nightly Iterator::array_chunks and friends, named
+// differently so they never clash with std if those are stabilised
+pub trait IterExt: Iterator + Sized {
+    // Non-overlapping [T; N] chunks, a short final chunk is kept as the remainder
+    fn chunks_exact_array<const N: usize>(self) -> ArrayChunks<Self, N> {
+        assert!(N != 0, "Chunk size must be non-zero");
+        ArrayChunks {
+            iter: self,
+            remainder: Vec::new(),
+        }
+    }
+
+    // Overlapping [T; N] windows, advancing one item at a time
+    fn windows_array<const N: usize>(self) -> ArrayWindows<Self, N>
+    where
+        Self::Item: Clone,
+    {
+        assert!(N != 0, "Window size must be non-zero");
+        ArrayWindows {
+            iter: self,
+            window: VecDeque::with_capacity(N),
+        }
+    }
+
+    // (a, b), (c, d), ...
+    fn pairs(self) -> Tuples<ArrayChunks<Self, 2>, Self::Item> {
+        self.chunks_exact_array::<2>().map(|[a, b]| (a, b))
+    }
+
+    // (a, b), (b, c), ...
+    fn tuple_windows(self) -> Tuples<ArrayWindows<Self, 2>, Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        self.windows_array::<2>().map(|[a, b]| (a, b))
+    }
+
+    // The k largest items, largest first, in O(n log k) time and O(k) space
+    fn top_k(self, k: usize) -> Vec<Self::Item>
+    where
+        Self::Item: Ord,
+    {
+        let mut top = TopK::new(k);
+        self.for_each(|item| top.push(item));
+        top.into_sorted_vec()
+    }
+}
+
+impl<I: Iterator> IterExt for I {}
+
+// Pairs of items from an iterator of [T; 2], as returned by pairs() and
+// tuple_windows()
+pub type Tuples<A, T> = Map<A, fn([T; 2]) -> (T, T)>;
+
+// Keeps the k largest items pushed so far. The smallest of those sits on top
+// of a min-heap, so each push is O(log k).
+pub struct TopK<T: Ord> {
+    k: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> TopK<T> {
+    pub fn new(k: usize) -> TopK<T> {
+        TopK {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, item: T) {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(item));
+        } else if self.heap.peek().is_some_and(|Reverse(min)| item > *min) {
+            self.heap.pop();
+            self.heap.push(Reverse(item));
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    // Largest first
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        // Sorting Reverse ascending puts the largest item first
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(item)| item)
+            .collect()
+    }
+}
+
+pub struct ArrayChunks<I: Iterator, const N: usize> {
+    iter: I,
+    remainder: Vec<I::Item>,
+}
+
+impl<I: Iterator, const N: usize> ArrayChunks<I, N> {
+    // Items left over once the iterator is exhausted, fewer than N of them
+    pub fn remainder(&self) -> &[I::Item] {
+        &self.remainder
+    }
+
+    pub fn into_remainder(self) -> Vec<I::Item> {
+        self.remainder
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for ArrayChunks<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk = self.iter.by_ref().take(N).collect::<Vec<I::Item>>();
+        match <[I::Item; N]>::try_from(chunk) {
+            Ok(chunk) => Some(chunk),
+            Err(rest) => {
+                if !rest.is_empty() {
+                    self.remainder = rest;
+                }
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower / N, upper.map(|u| u / N))
+    }
+}
+
+pub struct ArrayWindows<I: Iterator, const N: usize> {
+    iter: I,
+    window: VecDeque<I::Item>,
+}
+
+impl<I: Iterator, const N: usize> Iterator for ArrayWindows<I, N>
+where
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.window.len() == N {
+            self.window.pop_front();
+        }
+        while self.window.len() < N {
+            self.window.push_back(self.iter.next()?);
+        }
+
+        Some(std::array::from_fn(|i| self.window[i].clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Windows still to come once the buffer is topped up
+        let buffered = self.window.len().saturating_sub(1);
+        let (lower, upper) = self.iter.size_hint();
+        let windows = |n: usize| (n + buffered + 1).saturating_sub(N);
+        (windows(lower), upper.map(windows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks() {
+        let mut chunks = (1..=7).chunks_exact_array::<3>();
+        assert_eq!(chunks.size_hint(), (2, Some(2)));
+        assert_eq!(chunks.next(), Some([1, 2, 3]));
+        assert_eq!(chunks.next(), Some([4, 5, 6]));
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.remainder(), &[7]);
+
+        let mut chunks = "abcd".chars().chunks_exact_array::<2>();
+        assert_eq!(chunks.by_ref().count(), 2);
+        assert!(chunks.into_remainder().is_empty());
+    }
+
+    #[test]
+    fn windows() {
+        let windows = [1, 2, 3, 4].iter().windows_array::<3>();
+        assert_eq!(windows.size_hint(), (2, Some(2)));
+        assert_eq!(
+            windows.collect::<Vec<_>>(),
+            vec![[&1, &2, &3], [&2, &3, &4]]
+        );
+        assert_eq!((0..2).windows_array::<3>().next(), None);
+
+        let mut windows = (0..5).windows_array::<2>();
+        windows.next();
+        assert_eq!(windows.size_hint(), (3, Some(3)));
+    }
+
+    #[test]
+    fn top_k() {
+        let values = [5, 1, 9, 3, 9, 7, 2];
+        assert_eq!(values.iter().copied().top_k(3), vec![9, 9, 7]);
+        assert_eq!(values.iter().copied().top_k(10).len(), values.len());
+        assert!(values.iter().top_k(0).is_empty());
+
+        let mut top = TopK::new(2);
+        for word in ["pear", "apple", "quince", "fig"] {
+            top.push(word);
+        }
+        assert_eq!(top.len(), 2);
+        assert_eq!(top.into_sorted_vec(), vec!["quince", "pear"]);
+    }
+
+    #[test]
+    fn tuples() {
+        assert_eq!((1..6).pairs().collect::<Vec<_>>(), vec![(1, 2), (3, 4)]);
+        assert_eq!(
+            "abc".chars().tuple_windows().collect::<Vec<_>>(),
+            vec![('a', 'b'), ('b', 'c')]
+        );
+    }
+}
@@ -1 +1,17 @@
+pub mod answers;
+pub mod bench;
+pub mod charset;
+pub mod client;
+pub mod days;
+pub mod error;
+pub mod expr;
+pub mod files;
 pub mod geometry;
+pub mod input;
+pub mod iter;
+pub mod marker;
+pub mod ocr;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod submit;
@@ -0,0 +1,117 @@
+use crate::error::AocError;
+use std::io::{ErrorKind, Read};
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+// Finds the first run of N distinct bytes for several N at once. Each byte
+// is seen once: the table holds where every byte value last appeared, which
+// gives the length of the distinct run ending at the current byte.
+pub struct MarkerScanner {
+    sizes: Vec<usize>,
+    found: Vec<Option<u64>>,
+    // Offset + 1 of the last time each byte was seen, 0 for never
+    last_seen: [u64; 256],
+    run_start: u64,
+    offset: u64,
+}
+
+impl MarkerScanner {
+    pub fn new(sizes: &[usize]) -> MarkerScanner {
+        MarkerScanner {
+            sizes: sizes.to_vec(),
+            found: vec![None; sizes.len()],
+            last_seen: [0; 256],
+            run_start: 0,
+            offset: 0,
+        }
+    }
+
+    pub fn push(&mut self, byte: u8) {
+        let seen = &mut self.last_seen[byte as usize];
+        self.run_start = self.run_start.max(*seen);
+        self.offset += 1;
+        *seen = self.offset;
+
+        let run = self.offset - self.run_start;
+        for (size, found) in self.sizes.iter().zip(self.found.iter_mut()) {
+            if found.is_none() && run >= *size as u64 {
+                *found = Some(self.offset);
+            }
+        }
+    }
+
+    pub fn feed(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            if self.done() {
+                break;
+            }
+            self.push(*b);
+        }
+    }
+
+    pub fn done(&self) -> bool {
+        self.found.iter().all(|f| f.is_some())
+    }
+
+    // For each size, the offset just past the end of the first distinct run
+    pub fn markers(&self) -> &[Option<u64>] {
+        &self.found
+    }
+}
+
+// Stops reading as soon as every marker is found
+pub fn find_markers<R: Read>(mut reader: R, sizes: &[usize]) -> Result<Vec<Option<u64>>, AocError> {
+    let mut scanner = MarkerScanner::new(sizes);
+    let mut buf = vec![0; CHUNK_SIZE];
+
+    while !scanner.done() {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+        scanner.feed(&buf[..n]);
+    }
+
+    Ok(scanner.found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let examples = [
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ];
+
+        for (input, start, message) in examples {
+            let markers = find_markers(input.as_bytes(), &[4, 14]).unwrap();
+            assert_eq!(markers, vec![Some(start), Some(message)], "{input}");
+        }
+    }
+
+    #[test]
+    fn missing_and_trivial() {
+        assert_eq!(
+            find_markers("aabb".as_bytes(), &[3, 1]).unwrap(),
+            vec![None, Some(1)]
+        );
+        assert_eq!(find_markers("".as_bytes(), &[2]).unwrap(), vec![None]);
+    }
+
+    #[test]
+    fn across_chunks() {
+        // The only distinct run straddles a chunk boundary
+        let mut input = vec![b'a'; CHUNK_SIZE - 2];
+        input.extend_from_slice(b"bcd");
+        let markers = find_markers(input.as_slice(), &[4]).unwrap();
+        assert_eq!(markers, vec![Some(CHUNK_SIZE as u64 + 1)]);
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{
+    error::AocError,
+    geometry::{BoundingBox, Grid2d},
+};
+
+// The letters Advent of Code draws with lit pixels, each glyph's rows joined
+// together. 4x6 glyphs sit 5 columns apart, 6x10 glyphs 8 columns apart.
+struct Font {
+    width: usize,
+    height: usize,
+    stride: usize,
+    glyphs: &'static [(char, &'static str)],
+}
+
+const SMALL: Font = Font {
+    width: 4,
+    height: 6,
+    stride: 5,
+    glyphs: &[
+        ('A', ".##.#..##..######..##..#"),
+        ('B', "###.#..####.#..##..####."),
+        ('C', ".##.#..##...#...#..#.##."),
+        ('E', "#####...###.#...#...####"),
+        ('F', "#####...###.#...#...#..."),
+        ('G', ".##.#..##...#.###..#.###"),
+        ('H', "#..##..######..##..##..#"),
+        ('I', ".###..#...#...#...#..###"),
+        ('J', "..##...#...#...##..#.##."),
+        ('K', "#..##.#.##..#.#.#.#.#..#"),
+        ('L', "#...#...#...#...#...####"),
+        ('O', ".##.#..##..##..##..#.##."),
+        ('P', "###.#..##..####.#...#..."),
+        ('R', "###.#..##..####.#.#.#..#"),
+        ('S', ".####...#....##....####."),
+        ('U', "#..##..##..##..##..#.##."),
+        ('Z', "####...#..#..#..#...####"),
+    ],
+};
+
+const LARGE: Font = Font {
+    width: 6,
+    height: 10,
+    stride: 8,
+    glyphs: &[
+        (
+            'A',
+            "..##...#..#.#....##....##....########....##....##....##....#",
+        ),
+        (
+            'B',
+            "#####.#....##....##....######.#....##....##....##....######.",
+        ),
+        (
+            'C',
+            ".####.#....##.....#.....#.....#.....#.....#.....#....#.####.",
+        ),
+        (
+            'E',
+            "#######.....#.....#.....#####.#.....#.....#.....#.....######",
+        ),
+        (
+            'F',
+            "#######.....#.....#.....#####.#.....#.....#.....#.....#.....",
+        ),
+        (
+            'G',
+            ".####.#....##.....#.....#.....#..####....##....##...##.###.#",
+        ),
+        (
+            'H',
+            "#....##....##....##....########....##....##....##....##....#",
+        ),
+        (
+            'J',
+            "...###....#.....#.....#.....#.....#.....#.#...#.#...#..###..",
+        ),
+        (
+            'K',
+            "#....##...#.#..#..#.#...##....##....#.#...#..#..#...#.#....#",
+        ),
+        (
+            'L',
+            "#.....#.....#.....#.....#.....#.....#.....#.....#.....######",
+        ),
+        (
+            'N',
+            "#....###...###...##.#..##.#..##..#.##..#.##...###...###....#",
+        ),
+        (
+            'P',
+            "#####.#....##....##....######.#.....#.....#.....#.....#.....",
+        ),
+        (
+            'R',
+            "#####.#....##....##....######.#..#..#...#.#...#.#....##....#",
+        ),
+        (
+            'X',
+            "#....##....#.#..#..#..#...##....##...#..#..#..#.#....##....#",
+        ),
+        (
+            'Z',
+            "######.....#.....#....#....#....#....#....#.....#.....######",
+        ),
+    ],
+};
+
+// Reads the letters on a screen of lit pixels. Fully dark glyphs read as
+// spaces and are dropped from the end.
+pub fn decode(screen: &Grid2d<bool>) -> Result<String, AocError> {
+    let bounds = screen.bounds();
+    let height = bounds.height() as usize;
+    let font = [SMALL, LARGE]
+        .into_iter()
+        .find(|f| f.height == height)
+        .ok_or(AocError::Other(format!(
+            "Letters are 6 or 10 pixels tall, the screen is {height}"
+        )))?;
+
+    let pixel = |x: usize, y: usize| {
+        let lit = screen.index(bounds.tl.x + x as i32, bounds.tl.y + y as i32);
+        if *lit.unwrap_or(&false) {
+            '#'
+        } else {
+            '.'
+        }
+    };
+
+    let mut text = String::new();
+    let mut unknown = Vec::new();
+    for start in (0..bounds.width() as usize).step_by(font.stride) {
+        let glyph = (0..font.height)
+            .flat_map(|y| (start..start + font.width).map(move |x| (x, y)))
+            .map(|(x, y)| pixel(x, y))
+            .collect::<String>();
+
+        match font.glyphs.iter().find(|(_, g)| *g == glyph) {
+            Some((c, _)) => text.push(*c),
+            None if !glyph.contains('#') => text.push(' '),
+            None => {
+                unknown.push(start.to_string());
+                text.push('?');
+            }
+        }
+    }
+
+    if !unknown.is_empty() {
+        return Err(AocError::Other(format!(
+            "Unknown glyphs starting at columns {} in {:?}",
+            unknown.join(", "),
+            text.trim_end()
+        )));
+    }
+    Ok(String::from(text.trim_end()))
+}
+
+// The same for a drawing with # for lit pixels and . or spaces for dark ones
+pub fn decode_str(s: &str) -> Result<String, AocError> {
+    let rows = s
+        .lines()
+        .skip_while(|l| l.trim().is_empty())
+        .take_while(|l| !l.trim().is_empty())
+        .collect::<Vec<&str>>();
+    let width = rows.iter().map(|r| r.chars().count()).max().unwrap_or(0);
+
+    let mut screen = Grid2d::with_size(width, rows.len(), false);
+    for (y, row) in rows.iter().enumerate() {
+        for (x, c) in row.chars().enumerate() {
+            match c {
+                '#' => *screen.index_mut(x as i32, y as i32)? = true,
+                '.' | ' ' => {}
+                _ => return Err(AocError::parse(s, row, "Expected # or .")),
+            }
+        }
+    }
+
+    decode(&screen)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn letters() {
+        let screen = "
+#..#.####.###..###.
+#..#.#....#..#.#..#
+####.###..###..#..#
+#..#.#....#..#.###.
+#..#.#....#..#.#.#.
+#..#.####.###..#..#
+";
+        assert_eq!(decode_str(screen).unwrap(), "HEBR");
+
+        // Every glyph in the font reads back as itself
+        for font in [SMALL, LARGE] {
+            for (c, glyph) in font.glyphs {
+                let rows = glyph.as_bytes().chunks(font.width);
+                let drawing = rows
+                    .map(|r| String::from_utf8_lossy(r).into_owned())
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                assert_eq!(decode_str(&drawing).unwrap(), c.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_glyphs() {
+        let screen = "
+#..#.#####
+#..#.#####
+####.#####
+#..#.#####
+#..#.#####
+#..#.#####
+";
+        let e = decode_str(screen).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Unknown glyphs starting at columns 5 in \"H?\""
+        );
+        assert!(decode_str("#\n#\n").is_err());
+    }
+}
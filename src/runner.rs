@@ -0,0 +1,175 @@
+use crate::{
+    answers::{AnswerStore, Check},
+    days::{self, Day, Params},
+    error::AocError,
+};
+use std::fmt::Write;
+
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    // The error message when the part could not be solved
+    pub answer: Result<String, String>,
+    pub check: Check,
+}
+
+// "all" or a day number
+pub fn select_days(arg: &str) -> Result<Vec<&'static Day>, AocError> {
+    if arg == "all" {
+        return Ok(days::DAYS.iter().collect());
+    }
+
+    let day = arg
+        .parse::<u8>()
+        .map_err(|_| AocError::Other(format!("Expected a day number or 'all', got '{arg}'")))?;
+
+    days::get(day)
+        .map(|d| vec![d])
+        .ok_or(AocError::Other(format!("Day {day} is not solved yet")))
+}
+
+// Both parts of a day that could not be parsed or had no input
+pub fn failed(day: &Day, e: &AocError) -> Vec<PartResult> {
+    [1, 2]
+        .map(|part| PartResult {
+            day: day.day,
+            part,
+            answer: Err(e.to_string()),
+            check: Check::Unknown,
+        })
+        .into()
+}
+
+// A failing day becomes rows in the table rather than stopping the others
+pub fn solve(day: &Day, input: &str, store: &AnswerStore) -> Vec<PartResult> {
+    let params = Params::new();
+    let parsed = match (day.parse)(input) {
+        Ok(parsed) => parsed,
+        Err(e) => return failed(day, &e),
+    };
+    let parts = [(1, day.part1), (2, day.part2)];
+
+    parts
+        .iter()
+        .map(|(part, solve)| {
+            let answer = solve(&parsed, &params).map_err(|e| e.to_string());
+            PartResult {
+                day: day.day,
+                part: *part,
+                check: match &answer {
+                    Ok(a) => store.check(day.day, *part, a),
+                    Err(_) => Check::Unknown,
+                },
+                answer,
+            }
+        })
+        .collect()
+}
+
+// Multi-line answers (e.g. CRT output) do not fit a table cell
+fn cell(answer: &str) -> String {
+    if answer.trim_end().contains('\n') {
+        format!("<{} lines>", answer.lines().count())
+    } else {
+        String::from(answer)
+    }
+}
+
+pub fn table(results: &[PartResult], store: &AnswerStore) -> String {
+    let rows = results
+        .iter()
+        .map(|r| {
+            let expected = store.get(r.day, r.part).map(cell).unwrap_or_default();
+            let result = match (&r.answer, r.check) {
+                (Err(_), _) => "ERROR",
+                (_, Check::Pass) => "pass",
+                (_, Check::Fail) => "FAIL",
+                (_, Check::Unknown) => "-",
+            };
+            let answer = match &r.answer {
+                Ok(a) => cell(a),
+                // Parse errors carry an excerpt on the following lines
+                Err(e) => e.lines().next().unwrap_or_default().into(),
+            };
+            [
+                r.day.to_string(),
+                r.part.to_string(),
+                answer,
+                expected,
+                result.into(),
+            ]
+        })
+        .collect::<Vec<[String; 5]>>();
+
+    let header = ["Day", "Part", "Answer", "Expected", "Result"].map(String::from);
+    let mut widths = header.clone().map(|h| h.len());
+    for row in rows.iter() {
+        for (w, c) in widths.iter_mut().zip(row.iter()) {
+            *w = (*w).max(c.chars().count());
+        }
+    }
+
+    let mut s = String::new();
+    for row in std::iter::once(&header).chain(rows.iter()) {
+        let line = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(c, w)| format!("{c:<w$}"))
+            .collect::<Vec<String>>()
+            .join("  ");
+        writeln!(s, "{}", line.trim_end()).unwrap();
+    }
+
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn select() {
+        assert_eq!(select_days("all").unwrap().len(), days::DAYS.len());
+        assert_eq!(select_days("3").unwrap()[0].day, 3);
+        assert!(select_days("26").is_err());
+        assert!(select_days("three").is_err());
+    }
+
+    #[test]
+    fn render_table() {
+        let mut store = AnswerStore::new();
+        store.record(1, 1, "24000");
+        store.record(1, 2, "45001");
+
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+        let results = solve(days::get(1).unwrap(), input, &store);
+        assert_eq!(results[0].check, Check::Pass);
+        assert_eq!(results[1].check, Check::Fail);
+
+        assert_eq!(
+            table(&results, &store),
+            "Day  Part  Answer  Expected  Result\n\
+             1    1     24000   24000     pass\n\
+             1    2     45000   45001     FAIL\n"
+        );
+    }
+
+    #[test]
+    fn failed_day_is_a_row() {
+        let store = AnswerStore::new();
+        let mut results = solve(days::get(1).unwrap(), "1000\nabc\n", &store);
+        results.extend(solve(
+            days::get(6).unwrap(),
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+            &store,
+        ));
+        assert!(results[0].answer.is_err());
+        assert!(results[1].answer.is_err());
+        assert_eq!(results[2].answer.as_deref(), Ok("7"));
+
+        let table = table(&results, &store);
+        assert_eq!(table.lines().count(), 5);
+        assert!(table.lines().nth(1).unwrap().ends_with("ERROR"));
+        assert!(table.lines().nth(3).unwrap().ends_with("-"));
+    }
+}
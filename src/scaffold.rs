@@ -0,0 +1,185 @@
+use crate::error::AocError;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+// DAY is replaced with the day number
+const SOLUTION_TEMPLATE: &str = r#"use crate::error::AocError;
+
+pub fn parse(input: &str) -> Result<Vec<String>, AocError> {
+    Ok(input.lines().map(String::from).collect())
+}
+
+// Unsolved parts fail, so they can never be recorded or submitted
+pub fn part1(_lines: &[String]) -> Result<usize, AocError> {
+    Err(AocError::NoSolution(String::from("Day DAY part 1 is not solved yet")))
+}
+
+pub fn part2(_lines: &[String]) -> Result<usize, AocError> {
+    Err(AocError::NoSolution(String::from("Day DAY part 2 is not solved yet")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example answers go in fixtures/dayDAY.toml, checked by tests/examples.rs
+    #[test]
+    fn parse_example() {
+        let input = include_str!("../../fixtures/dayDAY.txt");
+        let lines = parse(input).unwrap();
+        assert_eq!(lines.len(), input.lines().count());
+    }
+}
+"#;
+
+const BINARY_TEMPLATE: &str = r#"use aoc2022::{days::dayDAY, error::AocError, input};
+
+fn main() -> Result<(), AocError> {
+    let input = input::load(DAY)?;
+    let parsed = dayDAY::parse(&input)?;
+
+    println!("Part 1: {}", dayDAY::part1(&parsed)?);
+    println!("Part 2: {}", dayDAY::part2(&parsed)?);
+    Ok(())
+}
+"#;
+
+const ANSWERS_TEMPLATE: &str = r#"# Expected answers for the example in dayDAY.txt, checked by tests/examples.rs
+# part1 = ""
+# part2 = ""
+"#;
+
+fn fill(template: &str, day: u8) -> String {
+    template.replace("DAY", &day.to_string())
+}
+
+// Add `pub mod dayN;` and the days::DAYS entry to the contents of days/mod.rs
+pub fn register(mod_rs: &str, day: u8) -> Result<String, AocError> {
+    let module = format!("day{day}");
+    let declaration = format!("pub mod {module};");
+    if mod_rs.lines().any(|l| l == declaration) {
+        return Err(AocError::Other(format!("Day {day} is already registered")));
+    }
+
+    // Modules are kept in the order rustfmt sorts them
+    let mut lines = mod_rs.lines().map(String::from).collect::<Vec<String>>();
+    let modules = lines
+        .iter()
+        .enumerate()
+        .filter(|l| l.1.starts_with("pub mod day"))
+        .map(|l| l.0)
+        .collect::<Vec<usize>>();
+    let name = |l: &str| String::from(l.trim_start_matches("pub mod ").trim_end_matches(';'));
+    let position = modules
+        .iter()
+        .find(|i| name(&lines[**i]) > module)
+        .copied()
+        .or(modules.last().map(|i| i + 1))
+        .ok_or("No day modules in days/mod.rs")?;
+    lines.insert(position, declaration);
+
+    let days_start = lines
+        .iter()
+        .position(|l| l.starts_with("pub static DAYS"))
+        .ok_or("No DAYS list in days/mod.rs")?;
+    let days_end = lines[days_start..]
+        .iter()
+        .position(|l| l == "];")
+        .ok_or("DAYS list is not terminated")?;
+    lines.insert(days_start + days_end, format!("    day!({day}, {module}),"));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn exists(path: &Path) -> AocError {
+    AocError::io(path, io::ErrorKind::AlreadyExists.into())
+}
+
+fn create(path: PathBuf, contents: &str) -> Result<PathBuf, AocError> {
+    if path.exists() {
+        return Err(exists(&path));
+    }
+
+    fs::write(&path, contents).map_err(|e| AocError::io(&path, e))?;
+    Ok(path)
+}
+
+// Create the solution, binary and example fixture for a new day in the
+// repository at root, then register the day. Returns the files touched.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, AocError> {
+    let mod_rs = root.join("src").join("days").join("mod.rs");
+    let registered = fs::read_to_string(&mod_rs)
+        .map_err(|e| AocError::io(&mod_rs, e))
+        .and_then(|s| register(&s, day))?;
+
+    let paths = [
+        root.join("src").join("days").join(format!("day{day}.rs")),
+        root.join("src").join("bin").join(format!("aoc{day}.rs")),
+        root.join("fixtures").join(format!("day{day}.txt")),
+        root.join("fixtures").join(format!("day{day}.toml")),
+    ];
+    if let Some(p) = paths.iter().find(|p| p.exists()) {
+        return Err(exists(p));
+    }
+
+    let templates = [SOLUTION_TEMPLATE, BINARY_TEMPLATE, "", ANSWERS_TEMPLATE];
+    let mut created = paths
+        .into_iter()
+        .zip(templates)
+        .map(|(path, template)| create(path, &fill(template, day)))
+        .collect::<Result<Vec<PathBuf>, AocError>>()?;
+
+    fs::write(&mod_rs, registered).map_err(|e| AocError::io(&mod_rs, e))?;
+    created.push(mod_rs);
+
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_day() {
+        let mod_rs = include_str!("days/mod.rs");
+        let registered = register(mod_rs, 16).unwrap();
+
+        assert!(registered.contains("pub mod day15;\npub mod day16;\npub mod day2;\n"));
+        assert!(registered.contains("    day!(16, day16),\n];\n"));
+        assert_eq!(registered.lines().count(), mod_rs.lines().count() + 2);
+        assert!(register(&registered, 16).is_err());
+
+        let registered = register(mod_rs, 25).unwrap();
+        assert!(registered.contains("pub mod day2;\npub mod day25;\npub mod day3;\n"));
+    }
+
+    #[test]
+    fn new_day_files() {
+        let root = std::env::temp_dir().join(format!("aoc2022-{}-scaffold", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for dir in ["src/days", "src/bin", "fixtures"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        fs::write(root.join("src/days/mod.rs"), include_str!("days/mod.rs")).unwrap();
+
+        let created = new_day(&root, 16).unwrap();
+        assert_eq!(created.len(), 5);
+
+        let solution = fs::read_to_string(root.join("src/days/day16.rs")).unwrap();
+        assert!(solution.contains("include_str!(\"../../fixtures/day16.txt\")"));
+        let binary = fs::read_to_string(root.join("src/bin/aoc16.rs")).unwrap();
+        assert!(binary.contains("input::load(16)"));
+        assert!(solution.contains("\"Day 16 part 2 is not solved yet\""));
+        // Solving a part must not break the generated test
+        assert!(!solution.contains("part1(&lines)"));
+        assert!(fs::read_to_string(root.join("fixtures/day16.txt"))
+            .unwrap()
+            .is_empty());
+
+        // Nothing is overwritten on a second run
+        assert!(new_day(&root, 16).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
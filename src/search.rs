@@ -0,0 +1,197 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<S> {
+    // From the start to the goal, both included
+    pub path: Vec<S>,
+    pub cost: u64,
+    // States taken off the queue, the goal included
+    pub visited: usize,
+}
+
+// Every state seen so far gets an index, so the queue and the parent links
+// hold indices rather than copies of the states
+struct Seen<S> {
+    states: Vec<S>,
+    index: HashMap<S, usize>,
+    parent: Vec<Option<usize>>,
+    cost: Vec<u64>,
+}
+
+impl<S: Clone + Hash + Eq> Seen<S> {
+    fn new(start: S) -> Seen<S> {
+        Seen {
+            index: HashMap::from([(start.clone(), 0)]),
+            states: vec![start],
+            parent: vec![None],
+            cost: vec![0],
+        }
+    }
+
+    // The index of s if it is new or now reached more cheaply
+    fn reach(&mut self, s: S, parent: usize, cost: u64) -> Option<usize> {
+        match self.index.get(&s) {
+            Some(&i) if self.cost[i] <= cost => None,
+            Some(&i) => {
+                self.parent[i] = Some(parent);
+                self.cost[i] = cost;
+                Some(i)
+            }
+            None => {
+                let i = self.states.len();
+                self.index.insert(s.clone(), i);
+                self.states.push(s);
+                self.parent.push(Some(parent));
+                self.cost.push(cost);
+                Some(i)
+            }
+        }
+    }
+
+    fn found(&self, goal: usize, visited: usize) -> Found<S> {
+        let cost = self.cost[goal];
+        let mut indices = vec![goal];
+        let mut i = goal;
+        while let Some(p) = self.parent[i] {
+            indices.push(p);
+            i = p;
+        }
+
+        let path = indices
+            .into_iter()
+            .rev()
+            .map(|i| self.states[i].clone())
+            .collect();
+        Found {
+            path,
+            cost,
+            visited,
+        }
+    }
+}
+
+// Fewest steps, every step costs 1
+pub fn bfs<S, I, F, G>(start: S, mut successors: F, mut goal: G) -> Option<Found<S>>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = S>,
+    F: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    let mut seen = Seen::new(start);
+    let mut queue = VecDeque::from([0]);
+    let mut visited = 0;
+
+    while let Some(i) = queue.pop_front() {
+        visited += 1;
+        if goal(&seen.states[i]) {
+            return Some(seen.found(i, visited));
+        }
+
+        let cost = seen.cost[i] + 1;
+        for next in successors(&seen.states[i]) {
+            if !seen.index.contains_key(&next) {
+                queue.extend(seen.reach(next, i, cost));
+            }
+        }
+    }
+
+    None
+}
+
+// Cheapest path when steps have different costs
+pub fn dijkstra<S, I, F, G>(start: S, successors: F, goal: G) -> Option<Found<S>>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = (S, u64)>,
+    F: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    astar(start, successors, goal, |_| 0)
+}
+
+// Dijkstra guided by a heuristic, which must never overestimate the cost
+// left to the goal for the path to be the cheapest
+pub fn astar<S, I, F, G, H>(
+    start: S,
+    mut successors: F,
+    mut goal: G,
+    mut heuristic: H,
+) -> Option<Found<S>>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = (S, u64)>,
+    F: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+    H: FnMut(&S) -> u64,
+{
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut seen = Seen::new(start);
+    let mut visited = 0;
+
+    while let Some(Reverse((_, cost, i))) = queue.pop() {
+        // Skip entries for states since reached more cheaply
+        if cost > seen.cost[i] {
+            continue;
+        }
+
+        visited += 1;
+        if goal(&seen.states[i]) {
+            return Some(seen.found(i, visited));
+        }
+
+        for (next, step) in successors(&seen.states[i]) {
+            let estimate = heuristic(&next);
+            if let Some(n) = seen.reach(next, i, cost + step) {
+                queue.push(Reverse((cost + step + estimate, cost + step, n)));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        // Not a grid: reach 10 from 1 by adding one or doubling
+        let next = |n: &u32| [n + 1, n * 2];
+        let found = bfs(1, next, |n| *n == 10).unwrap();
+        assert_eq!(found.path, vec![1, 2, 4, 5, 10]);
+        assert_eq!(found.cost, 4);
+
+        // Doubling is now dearer than adding
+        let next = |n: &u32| [(n + 1, 1), (n * 2, 3)];
+        let found = dijkstra(1, next, |n| *n == 10).unwrap();
+        assert_eq!(found.cost, 7);
+        assert_eq!(found.path, vec![1, 2, 3, 4, 5, 10]);
+
+        assert!(bfs(1, |n: &u32| (*n < 5).then_some(n + 1), |n| *n == 10).is_none());
+    }
+
+    #[test]
+    fn heuristic() {
+        // An open 20x20 grid, the heuristic lets A* go straight for the goal
+        let next = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|(x, y)| (0..20).contains(x) && (0..20).contains(y))
+                .map(|p| (p, 1))
+        };
+        let goal = (19, 0);
+        let manhattan = |&(x, y): &(i32, i32)| (goal.0 - x).unsigned_abs() as u64 + y as u64;
+
+        let plain = dijkstra((0, 0), next, |p| *p == goal).unwrap();
+        let guided = astar((0, 0), next, |p| *p == goal, manhattan).unwrap();
+        assert_eq!((plain.cost, guided.cost), (19, 19));
+        assert_eq!(guided.path.len(), 20);
+        assert!(guided.visited < plain.visited);
+    }
+}
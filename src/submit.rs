@@ -0,0 +1,218 @@
+use crate::{answers::AnswerStore, client::Client, error::AocError};
+use regex::Regex;
+use std::{
+    fmt::Display,
+    time::{Duration, SystemTime},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong(Option<Hint>),
+    // Submitted too soon after the last answer, nothing was checked
+    Wait(Duration),
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::Wrong(None) => write!(f, "That's not the right answer"),
+            Verdict::Wrong(Some(Hint::TooHigh)) => write!(f, "Wrong, the answer is too high"),
+            Verdict::Wrong(Some(Hint::TooLow)) => write!(f, "Wrong, the answer is too low"),
+            Verdict::Wait(d) => write!(f, "Too soon, wait {} seconds", d.as_secs()),
+            Verdict::AlreadySolved => write!(f, "This part is already solved"),
+            Verdict::Unknown(s) => write!(f, "Unexpected response: {s}"),
+        }
+    }
+}
+
+fn wait_time(page: &str) -> Option<Duration> {
+    // You gave an answer too recently; you have 1m 23s left to wait.
+    let re = Regex::new(r"you have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    let caps = re.captures(page)?;
+    let minutes = caps
+        .get(1)
+        .map_or(0, |m| m.as_str().parse::<u64>().unwrap());
+    let seconds = caps[2].parse::<u64>().unwrap();
+
+    Some(Duration::from_secs(minutes * 60 + seconds))
+}
+
+// Wrong answers also lock out the next try, e.g. "Please wait one minute
+// before trying again." or "please wait 5 minutes before trying again."
+fn retry_after(page: &str) -> Option<Duration> {
+    let re = Regex::new(r"(?i)please wait (one|\d+) minutes? before trying again").unwrap();
+    let minutes = match &re.captures(page)?[1] {
+        "one" => 1,
+        n => n.parse::<u64>().unwrap(),
+    };
+
+    Some(Duration::from_secs(minutes * 60))
+}
+
+// Read the verdict from the page the site answers a submission with
+pub fn parse_verdict(page: &str) -> Verdict {
+    if page.contains("That's the right answer") {
+        Verdict::Correct
+    } else if page.contains("That's not the right answer") {
+        let hint = if page.contains("your answer is too high") {
+            Some(Hint::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        Verdict::Wrong(hint)
+    } else if let Some(wait) = wait_time(page) {
+        Verdict::Wait(wait)
+    } else if page.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        let text = Regex::new(r"<[^>]*>").unwrap().replace_all(page, "");
+        Verdict::Unknown(String::from(text.trim()))
+    }
+}
+
+// Submit an answer unless the store already knows whether it is right or the
+// site asked to wait, then record the verdict in the store
+pub fn submit(
+    client: &Client,
+    store: &mut AnswerStore,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Verdict, AocError> {
+    if store.is_wrong(day, part, answer) {
+        return Err(AocError::Other(format!(
+            "{answer} was already rejected for day {day} part {part}"
+        )));
+    }
+
+    match store.get(day, part) {
+        Some(known) if known == answer => return Ok(Verdict::Correct),
+        Some(known) => {
+            return Err(AocError::Other(format!(
+                "Day {day} part {part} was already solved with {known}"
+            )))
+        }
+        None => {}
+    }
+
+    let now = SystemTime::now();
+    if let Some(left) = store.wait_until().and_then(|t| t.duration_since(now).ok()) {
+        return Err(AocError::Other(format!(
+            "Too soon to answer again, wait {} seconds",
+            left.as_secs().max(1)
+        )));
+    }
+
+    let page = client.submit(day, part, answer)?;
+    let verdict = parse_verdict(&page);
+    match verdict {
+        Verdict::Correct => store.record(day, part, answer),
+        Verdict::Wrong(_) => {
+            store.record_wrong(day, part, answer);
+            if let Some(d) = retry_after(&page) {
+                store.set_wait_until(now + d);
+            }
+        }
+        Verdict::Wait(d) => store.set_wait_until(now + d),
+        _ => {}
+    }
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verdicts() {
+        let page = |s: &str| format!("<main>\n<article><p>{s}</p></article>\n</main>");
+
+        assert_eq!(
+            parse_verdict(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Verdict::Correct
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            )),
+            Verdict::Wrong(Some(Hint::TooHigh))
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Verdict::Wrong(Some(Hint::TooLow))
+        );
+        assert_eq!(
+            parse_verdict(&page("That's not the right answer. If you're stuck...")),
+            Verdict::Wrong(None)
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "You gave an answer too recently; you have 1m 23s left to wait."
+            )),
+            Verdict::Wait(Duration::from_secs(83))
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "You gave an answer too recently; you have 9s left to wait."
+            )),
+            Verdict::Wait(Duration::from_secs(9))
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Verdict::AlreadySolved
+        );
+        assert_eq!(
+            parse_verdict(&page("Something else")),
+            Verdict::Unknown(String::from("Something else"))
+        );
+
+        assert_eq!(
+            retry_after(&page("Please wait one minute before trying again.")),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            retry_after(&page("please wait 5 minutes before trying again.")),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(retry_after(&page("That's not the right answer.")), None);
+    }
+
+    #[test]
+    fn refused_locally() {
+        // Nothing listens here, so any request would fail
+        let client = Client::new("http://127.0.0.1:1", None);
+        let mut store = AnswerStore::new();
+        store.record(1, 1, "24000");
+        store.record_wrong(1, 2, "45001");
+
+        assert_eq!(
+            submit(&client, &mut store, 1, 1, "24000").unwrap(),
+            Verdict::Correct
+        );
+        assert!(submit(&client, &mut store, 1, 1, "24001").is_err());
+        assert!(submit(&client, &mut store, 1, 2, "45001").is_err());
+
+        store.set_wait_until(SystemTime::now() + Duration::from_secs(60));
+        let e = submit(&client, &mut store, 1, 2, "45000").unwrap_err();
+        assert!(e.to_string().starts_with("Too soon"), "{e}");
+    }
+}
@@ -0,0 +1,107 @@
+// A tiny stand-in for the Advent of Code site, so the HTTP client can be
+// tested without a network. Each test file uses a different part of it.
+#![allow(dead_code)]
+
+use std::{
+    env, fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|h| h.0.eq_ignore_ascii_case(name))
+            .map(|h| h.1.as_str())
+    }
+}
+
+pub struct StandIn {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl StandIn {
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+// Answer each incoming request with the next (status, body) in order
+pub fn serve(responses: Vec<(u16, &str)>) -> StandIn {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let responses = responses
+        .into_iter()
+        .map(|(code, body)| (code, String::from(body)))
+        .collect::<Vec<(u16, String)>>();
+
+    let recorded = requests.clone();
+    thread::spawn(move || {
+        for (code, body) in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let mut parts = line.split_whitespace();
+            let method = String::from(parts.next().unwrap_or(""));
+            let path = String::from(parts.next().unwrap_or(""));
+
+            let mut headers = Vec::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let Some((name, value)) = line.trim_end().split_once(": ") else {
+                    break;
+                };
+                headers.push((String::from(name), String::from(value)));
+            }
+
+            let len = headers
+                .iter()
+                .find(|h| h.0.eq_ignore_ascii_case("content-length"))
+                .map(|h| h.1.parse::<usize>().unwrap())
+                .unwrap_or(0);
+            let mut body_in = vec![0; len];
+            reader.read_exact(&mut body_in).unwrap();
+
+            recorded.lock().unwrap().push(Request {
+                method,
+                path,
+                headers,
+                body: String::from_utf8(body_in).unwrap(),
+            });
+
+            write!(
+                stream,
+                "HTTP/1.1 {code} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+
+    StandIn { url, requests }
+}
+
+// Fresh, empty scratch directory for a test
+pub fn scratch_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc2022-{}-{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
@@ -0,0 +1,84 @@
+use aoc2022::days::{self, Params};
+use std::{fs, path::PathBuf};
+use toml::Value;
+
+// Puzzle example for a day: fixtures/dayN.txt holds the input and
+// fixtures/dayN.toml the expected answers and any puzzle parameters
+struct Example {
+    input: String,
+    part1: Option<String>,
+    part2: Option<String>,
+    params: Params,
+}
+
+fn fixture_path(day: u8, ext: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures")
+        .join(format!("day{day}.{ext}"))
+}
+
+fn load_example(day: u8) -> Example {
+    let input = fs::read_to_string(fixture_path(day, "txt"))
+        .unwrap_or_else(|_| panic!("Missing example input for day {day}"));
+    let answers = fs::read_to_string(fixture_path(day, "toml"))
+        .unwrap_or_else(|_| panic!("Missing example answers for day {day}"))
+        .parse::<Value>()
+        .unwrap_or_else(|e| panic!("Invalid example answers for day {day}: {e}"));
+
+    let mut params = Params::new();
+    if let Some(table) = answers.get("params").and_then(|p| p.as_table()) {
+        for (key, value) in table {
+            params.set(key, value.as_integer().expect("Params must be integers"));
+        }
+    }
+
+    Example {
+        input,
+        part1: answers
+            .get("part1")
+            .and_then(|a| a.as_str())
+            .map(String::from),
+        part2: answers
+            .get("part2")
+            .and_then(|a| a.as_str())
+            .map(String::from),
+        params,
+    }
+}
+
+#[test]
+fn examples() {
+    let mut failures = Vec::new();
+
+    for day in days::DAYS.iter() {
+        let example = load_example(day.day);
+        let parsed = match (day.parse)(&example.input) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                failures.push(format!("Day {}: {e}", day.day));
+                continue;
+            }
+        };
+        let parts = [
+            (1, day.part1, &example.part1),
+            (2, day.part2, &example.part2),
+        ];
+
+        for (part, solve, expected) in parts {
+            // Parts without an expected answer are not solved yet
+            let Some(expected) = expected else {
+                continue;
+            };
+
+            let actual = solve(&parsed, &example.params).unwrap_or_else(|e| e.to_string());
+            if actual != *expected {
+                failures.push(format!(
+                    "Day {} part {part}: expected {expected:?}, got {actual:?}",
+                    day.day
+                ));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use aoc2022::{client::Client, input};
+use std::fs;
+
+#[test]
+fn downloads_and_caches_input() {
+    let server = common::serve(vec![(200, "1000\n2000\n")]);
+    let client = Client::new(&server.url, Some("abc123\n"));
+    let dir = common::scratch_dir("fetch-cache");
+
+    assert_eq!(input::load_with(&client, &dir, 1).unwrap(), "1000\n2000\n");
+    assert_eq!(
+        fs::read_to_string(input::path(&dir, 1)).unwrap(),
+        "1000\n2000\n"
+    );
+
+    // Served from the cache, the stand-in only answers once
+    assert_eq!(input::load_with(&client, &dir, 1).unwrap(), "1000\n2000\n");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2022/day/1/input");
+    assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+    assert!(requests[0].header("user-agent").is_some());
+}
+
+#[test]
+fn reports_server_errors() {
+    let server = common::serve(vec![(404, "Not found\n")]);
+    let client = Client::new(&server.url, Some("abc123"));
+    let dir = common::scratch_dir("fetch-error");
+
+    let err = input::load_with(&client, &dir, 25).unwrap_err().to_string();
+    assert!(err.contains("404"), "{err}");
+    assert!(!input::path(&dir, 25).exists());
+}
+
+#[test]
+fn needs_a_session() {
+    let client = Client::new("http://127.0.0.1:9", None);
+    let dir = common::scratch_dir("fetch-session");
+
+    let err = input::load_with(&client, &dir, 3).unwrap_err().to_string();
+    assert!(err.contains("session"), "{err}");
+}
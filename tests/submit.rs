@@ -0,0 +1,82 @@
+mod common;
+
+use aoc2022::{
+    answers::AnswerStore,
+    client::Client,
+    submit::{self, Hint, Verdict},
+};
+use std::time::{Duration, UNIX_EPOCH};
+
+const WRONG: &str = "<article><p>That's not the right answer; your answer is too low. \
+    Please wait one minute before trying again.</p></article>";
+const WAIT: &str = "<article><p>You gave an answer too recently; you have 42s left to wait. \
+    </p></article>";
+const RIGHT: &str = "<article><p>That's the right answer! You are one gold star closer.</p>\
+    </article>";
+
+#[test]
+fn never_resends_a_wrong_answer() {
+    let server = common::serve(vec![(200, WRONG)]);
+    let client = Client::new(&server.url, Some("abc123"));
+    let mut store = AnswerStore::new();
+
+    let verdict = submit::submit(&client, &mut store, 14, 2, "93").unwrap();
+    assert_eq!(verdict, Verdict::Wrong(Some(Hint::TooLow)));
+    assert!(store.is_wrong(14, 2, "93"));
+
+    // Refused locally, the stand-in only answers once
+    assert!(submit::submit(&client, &mut store, 14, 2, "93").is_err());
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2022/day/14/answer");
+    assert_eq!(requests[0].body, "level=2&answer=93");
+    assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+}
+
+#[test]
+fn wrong_answer_starts_the_wait() {
+    let server = common::serve(vec![(200, WRONG), (200, RIGHT)]);
+    let client = Client::new(&server.url, Some("abc123"));
+    let mut store = AnswerStore::new();
+
+    let verdict = submit::submit(&client, &mut store, 14, 2, "93").unwrap();
+    assert_eq!(verdict, Verdict::Wrong(Some(Hint::TooLow)));
+    assert!(store.wait_until().is_some());
+
+    // A different answer is still refused locally for the next minute
+    let e = submit::submit(&client, &mut store, 14, 2, "94").unwrap_err();
+    assert!(e.to_string().starts_with("Too soon"), "{e}");
+    assert_eq!(server.requests().len(), 1);
+
+    store.set_wait_until(UNIX_EPOCH);
+    let verdict = submit::submit(&client, &mut store, 14, 2, "94").unwrap();
+    assert_eq!(verdict, Verdict::Correct);
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn rate_limit_is_not_a_wrong_answer() {
+    let server = common::serve(vec![(200, WAIT), (200, RIGHT)]);
+    let client = Client::new(&server.url, Some("abc123"));
+    let mut store = AnswerStore::new();
+
+    let verdict = submit::submit(&client, &mut store, 1, 1, "24000").unwrap();
+    assert_eq!(verdict, Verdict::Wait(Duration::from_secs(42)));
+    assert!(!store.is_wrong(1, 1, "24000"));
+
+    // Refused locally until the wait is over
+    assert!(submit::submit(&client, &mut store, 1, 1, "24000").is_err());
+    assert_eq!(server.requests().len(), 1);
+    store.set_wait_until(UNIX_EPOCH);
+
+    let verdict = submit::submit(&client, &mut store, 1, 1, "24000").unwrap();
+    assert_eq!(verdict, Verdict::Correct);
+    assert_eq!(store.get(1, 1), Some("24000"));
+
+    // Known right answers are not sent again
+    let verdict = submit::submit(&client, &mut store, 1, 1, "24000").unwrap();
+    assert_eq!(verdict, Verdict::Correct);
+    assert_eq!(server.requests().len(), 2);
+}